@@ -0,0 +1,95 @@
+//! Compares the reduction strategies of `PrimeField` on 255- and 256-bit primes.
+//!
+//! With `BigInt`, a single `mod_floor` beats Montgomery, Barrett and the
+//! special forms, which are built from several big-integer shifts, masks and
+//! products, so curves default to `Generic`; the ratios
+//! printed next to each time are relative to `Generic`.
+//!
+//! Run with `cargo bench --bench field_bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use KissECC::curves;
+use KissECC::ecc::EllipticCurve;
+use KissECC::field::{PrimeField, Reduction};
+use KissECC::scalar::Scalar;
+use KissECC::utils::Utils;
+use num_bigint::BigInt;
+
+const FIELD_MULS: u32 = 200_000;
+const SCALAR_MULS: u32 = 5;
+
+/// Times a chain of dependent multiplications x ← x·y in F_q.
+fn bench_field_mul(q: &BigInt, reduction: Reduction) -> Duration {
+    let f = PrimeField::with_reduction(q.clone(), reduction);
+    // The coordinates of the P-256 generator, as two arbitrary field elements.
+    let g = curves::p256::<BigInt>().generator;
+    let mut x = f.element(g.x);
+    let y = f.element(g.y);
+    let start = Instant::now();
+    for _ in 0..FIELD_MULS {
+        x = black_box(&x * &y);
+    }
+    black_box(x);
+    start.elapsed()
+}
+
+/// Times scalar multiplications on P-256 with the given reduction.
+fn bench_scalar_mul(reduction: Reduction) -> Duration {
+    let p256 = curves::p256::<BigInt>();
+    let ecc = p256.curve.with_reduction(reduction);
+    let scalar = Scalar::new(Utils::from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"));
+    let start = Instant::now();
+    for _ in 0..SCALAR_MULS {
+        black_box(ecc.mul(black_box(&scalar), &p256.generator));
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let q = curves::p256::<BigInt>().curve.q;
+    let strategies = [
+        Reduction::Generic,
+        Reduction::Montgomery,
+        Reduction::Barrett,
+        Reduction::Solinas,
+    ];
+
+    println!("P-256 field multiplication ({FIELD_MULS} iterations)");
+    let baseline = bench_field_mul(&q, Reduction::Generic);
+    for reduction in strategies {
+        let t = bench_field_mul(&q, reduction);
+        println!(
+            "  {:<14} {:>10.1} ns/op  {:>5.2}x",
+            format!("{reduction:?}"),
+            t.as_nanos() as f64 / FIELD_MULS as f64,
+            baseline.as_secs_f64() / t.as_secs_f64()
+        );
+    }
+
+    let p25519 = curves::curve25519::<BigInt>().curve.q;
+    println!("2^255 - 19 field multiplication ({FIELD_MULS} iterations)");
+    let baseline = bench_field_mul(&p25519, Reduction::Generic);
+    for reduction in [Reduction::Generic, Reduction::Montgomery, Reduction::PseudoMersenne] {
+        let t = bench_field_mul(&p25519, reduction);
+        println!(
+            "  {:<14} {:>10.1} ns/op  {:>5.2}x",
+            format!("{reduction:?}"),
+            t.as_nanos() as f64 / FIELD_MULS as f64,
+            baseline.as_secs_f64() / t.as_secs_f64()
+        );
+    }
+
+    println!("P-256 scalar multiplication by a 256-bit scalar ({SCALAR_MULS} iterations)");
+    let baseline = bench_scalar_mul(Reduction::Generic);
+    for reduction in strategies {
+        let t = bench_scalar_mul(reduction);
+        println!(
+            "  {:<14} {:>10.1} µs/op  {:>5.2}x",
+            format!("{reduction:?}"),
+            t.as_micros() as f64 / SCALAR_MULS as f64,
+            baseline.as_secs_f64() / t.as_secs_f64()
+        );
+    }
+}
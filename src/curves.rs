@@ -0,0 +1,609 @@
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::ecc::EllipticCurve;
+use crate::edwards_curve::EdwardsCurve;
+use crate::error::EccError;
+use crate::montgomery_curve::MontgomeryCurve;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use crate::twisted_curve::TwistedCurve;
+use crate::utils::Utils;
+use crate::weierstrass_ecc::WeierstrassECC;
+
+/// A standard curve together with its base point.
+///
+/// `order` is the prime order n of the generator and `cofactor` is h, so that
+/// the curve has h*n points. All named curves need integers of several hundred
+/// bits, so `T` is in practice `BigInt`.
+pub struct NamedCurve<C, T> {
+    pub name: &'static str,
+    pub curve: C,
+    pub generator: Point<T>,
+    pub order: T,
+    pub cofactor: T,
+}
+
+impl<C, T> NamedCurve<C, T>
+where
+    C: EllipticCurve<T>,
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    /// Checks that the generator G is a finite point of the curve of order n.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NotOnCurve` if G is not on the curve or is the
+    /// identity, and `EccError::InvalidParameters` if the order of G is not
+    /// exactly n.
+    pub fn check(&self) -> Result<(), EccError> {
+        check_generator(&self.curve, &self.generator, &self.order)
+    }
+}
+
+/// Checks that `g` is a finite point of `curve` of order exactly n: n*g is the
+/// identity and (n/p)*g is not for any prime p dividing n. Factoring n is
+/// instant when n is prime, as for every named curve; see [`NamedCurve::check`].
+pub(crate) fn check_generator<T>(curve: &dyn EllipticCurve<T>, g: &Point<T>, n: &T) -> Result<(), EccError>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    if !curve.is_valid(g) || curve.is_identity(g) {
+        return Err(EccError::NotOnCurve);
+    }
+    let wrong_order = EccError::InvalidParameters("the generator does not have the stated order");
+    if !curve.is_identity(&curve.mul(&Scalar::new(n.clone()), g)) {
+        return Err(wrong_order);
+    }
+    for (p, _) in Utils::factor(n) {
+        if curve.is_identity(&curve.mul(&Scalar::new(n.clone() / p), g)) {
+            return Err(wrong_order);
+        }
+    }
+    Ok(())
+}
+
+/// NIST P-192 (FIPS 186-4 §D.1.2.1): y² = x³ − 3x + b over 2^192 − 2^64 − 1.
+pub fn p192<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = (T::one() << 192) - (T::one() << 64) - T::one();
+    weierstrass(
+        "P-192",
+        q.clone() - T::from(3u8),
+        Utils::from_hex("64210519e59c80e70fa7e9ab72243049feb8deecc146b9b1"),
+        q,
+        (
+            "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+            "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+        ),
+        "ffffffffffffffffffffffff99def836146bc9b1b4d22831",
+    )
+}
+
+/// NIST P-224 (FIPS 186-4 §D.1.2.2): y² = x³ − 3x + b over 2^224 − 2^96 + 1.
+pub fn p224<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = (T::one() << 224) - (T::one() << 96) + T::one();
+    weierstrass(
+        "P-224",
+        q.clone() - T::from(3u8),
+        Utils::from_hex("b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4"),
+        q,
+        (
+            "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+            "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+        ),
+        "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+    )
+}
+
+/// NIST P-256 (FIPS 186-4 §D.1.2.3): y² = x³ − 3x + b over 2^256 − 2^224 + 2^192 + 2^96 − 1.
+pub fn p256<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = (T::one() << 256) - (T::one() << 224) + (T::one() << 192) + (T::one() << 96) - T::one();
+    weierstrass(
+        "P-256",
+        q.clone() - T::from(3u8),
+        Utils::from_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b"),
+        q,
+        (
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        ),
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    )
+}
+
+/// NIST P-384 (FIPS 186-4 §D.1.2.4): y² = x³ − 3x + b over 2^384 − 2^128 − 2^96 + 2^32 − 1.
+pub fn p384<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = (T::one() << 384) - (T::one() << 128) - (T::one() << 96) + (T::one() << 32) - T::one();
+    weierstrass(
+        "P-384",
+        q.clone() - T::from(3u8),
+        Utils::from_hex("b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef"),
+        q,
+        (
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+        ),
+        "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+    )
+}
+
+/// NIST P-521 (FIPS 186-4 §D.1.2.5): y² = x³ − 3x + b over 2^521 − 1.
+pub fn p521<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = (T::one() << 521) - T::one();
+    weierstrass(
+        "P-521",
+        q.clone() - T::from(3u8),
+        Utils::from_hex("0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00"),
+        q,
+        (
+            "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+            "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+        ),
+        "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+    )
+}
+
+/// secp256k1 (SEC 2 §2.4.1): y² = x³ + 7 over 2^256 − 2^32 − 977.
+pub fn secp256k1<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = (T::one() << 256) - (T::one() << 32) - T::from_u32(977).unwrap();
+    weierstrass(
+        "secp256k1",
+        T::zero(),
+        T::from(7u8),
+        q,
+        (
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        ),
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+    )
+}
+
+/// brainpoolP256r1 (RFC 5639 §3.4).
+pub fn brainpool_p256r1<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    weierstrass(
+        "brainpoolP256r1",
+        Utils::from_hex("7d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9"),
+        Utils::from_hex("26dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b6"),
+        Utils::from_hex("a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377"),
+        (
+            "8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262",
+            "547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997",
+        ),
+        "a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7",
+    )
+}
+
+/// brainpoolP384r1 (RFC 5639 §3.6).
+pub fn brainpool_p384r1<T>() -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    weierstrass(
+        "brainpoolP384r1",
+        Utils::from_hex("7bc382c63d8c150c3c72080ace05afa0c2bea28e4fb22787139165efba91f90f8aa5814a503ad4eb04a8c7dd22ce2826"),
+        Utils::from_hex("04a8c7dd22ce28268b39b55416f0447c2fb77de107dcd2a62e880ea53eeb62d57cb4390295dbc9943ab78696fa504c11"),
+        Utils::from_hex("8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec53"),
+        (
+            "1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e",
+            "8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315",
+        ),
+        "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565",
+    )
+}
+
+/// Curve25519 (RFC 7748 §4.1): v² = u³ + 486662u² + u over 2^255 − 19, base u = 9.
+pub fn curve25519<T>() -> NamedCurve<MontgomeryCurve<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let order: T = order25519();
+    NamedCurve {
+        name: "Curve25519",
+        curve: MontgomeryCurve::new(T::from_u32(486662).unwrap(), T::one(), p25519(), order.clone()),
+        generator: Point::new(
+            T::from(9u8),
+            Utils::from_hex("20ae19a1b8a086b4e01edd2c7748d14c923d4d7e6d7c61b229e9c5a27eced3d9"),
+        ),
+        order,
+        cofactor: T::from(8u8),
+    }
+}
+
+/// Curve448 (RFC 7748 §4.2): v² = u³ + 156326u² + u over 2^448 − 2^224 − 1, base u = 5.
+pub fn curve448<T>() -> NamedCurve<MontgomeryCurve<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let order: T = order448();
+    NamedCurve {
+        name: "Curve448",
+        curve: MontgomeryCurve::new(T::from_u32(156326).unwrap(), T::one(), p448(), order.clone()),
+        generator: Point::new(
+            T::from(5u8),
+            Utils::from_hex("7d235d1295f5b1f66c98ab6e58326fcecbae5d34f55545d060f75dc28df3f6edb8027e2346430d211312c4b150677af76fd7223d457b5b1a"),
+        ),
+        order,
+        cofactor: T::from(4u8),
+    }
+}
+
+/// edwards25519 (RFC 8032 §5.1): −x² + y² = 1 + dx²y² over 2^255 − 19,
+/// d = −121665/121666, base point with y = 4/5.
+pub fn ed25519<T>() -> NamedCurve<TwistedCurve<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = p25519::<T>();
+    let order: T = order25519();
+    NamedCurve {
+        name: "Ed25519",
+        curve: TwistedCurve::new(
+            q.clone() - T::one(),
+            Utils::from_hex("52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3"),
+            q,
+            order.clone(),
+        ),
+        generator: Point::new(
+            Utils::from_hex("216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a"),
+            Utils::from_hex("6666666666666666666666666666666666666666666666666666666666666658"),
+        ),
+        order,
+        cofactor: T::from(8u8),
+    }
+}
+
+/// Ed448-Goldilocks (RFC 8032 §5.2): x² + y² = 1 − 39081x²y² over 2^448 − 2^224 − 1.
+pub fn ed448<T>() -> NamedCurve<EdwardsCurve<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let q = p448::<T>();
+    NamedCurve {
+        name: "Ed448-Goldilocks",
+        curve: EdwardsCurve::new(T::one(), q.clone() - T::from_u32(39081).unwrap(), q),
+        generator: Point::new(
+            Utils::from_hex("4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e"),
+            Utils::from_hex("693f46716eb6bc248876203756c9c7624bea73736ca3984087789c1e05a0c2d73ad3ff1ce67c39c4fdbd132c4ed7c8ad9808795bf230fa14"),
+        ),
+        order: order448(),
+        cofactor: T::from(4u8),
+    }
+}
+
+/// A Weierstrass curve with cofactor 1 from hexadecimal constants.
+fn weierstrass<T>(name: &'static str, a: T, b: T, q: T, g: (&str, &str), n: &str) -> NamedCurve<WeierstrassECC<T>, T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    NamedCurve {
+        name,
+        curve: WeierstrassECC::new(a, b, q),
+        generator: Point::new(Utils::from_hex(g.0), Utils::from_hex(g.1)),
+        order: Utils::from_hex(n),
+        cofactor: T::one(),
+    }
+}
+
+/// 2^255 − 19.
+fn p25519<T>() -> T
+where
+    T: One + From<u8> + Sub<Output = T> + Shl<u32, Output = T>,
+{
+    (T::one() << 255) - T::from(19u8)
+}
+
+/// 2^448 − 2^224 − 1.
+fn p448<T>() -> T
+where
+    T: One + Sub<Output = T> + Shl<u32, Output = T>,
+{
+    (T::one() << 448) - (T::one() << 224) - T::one()
+}
+
+/// The prime order 2^252 + 27742317777372353535851937790883648493 of the
+/// edwards25519 and Curve25519 base points.
+fn order25519<T>() -> T
+where
+    T: Zero + One + FromPrimitive + Add<Output = T> + Shl<u32, Output = T>,
+{
+    (T::one() << 252) + Utils::from_hex::<T>("14def9dea2f79cd65812631a5cf5d3ed")
+}
+
+/// The prime order of the Ed448-Goldilocks and Curve448 base points.
+fn order448<T>() -> T
+where
+    T: Zero + FromPrimitive + Add<Output = T> + Shl<u32, Output = T>,
+{
+    Utils::from_hex("3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3")
+}
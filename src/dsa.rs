@@ -1,9 +1,14 @@
+use crate::curves::check_generator;
 use crate::ecc::{EllipticCurve};
+use crate::error::EccError;
 use crate::point::Point;
+use crate::rfc6979::{bits2int, NonceGenerator};
+use crate::scalar::Scalar;
 use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
 use num_integer::Integer;
-use rand::Rng;
-use std::ops::{Add, Sub, Mul, Rem, Div};
+use sha2::digest::core_api::BlockSizeUser;
+use sha2::{Digest, Sha256};
+use std::ops::{Add, Sub, Mul, Rem, Div, Shl, Shr, BitAnd};
 
 pub struct DSA<T> {
     pub g: Point<T>,
@@ -15,7 +20,6 @@ impl<T> DSA<T>
 where
     T: One
     + Zero
-    + Copy
     + PartialEq
     + Clone
     + PartialOrd
@@ -28,29 +32,196 @@ where
     + Mul<Output = T>
     + Rem<Output = T>
 
-    + Div<Output = T>,
+    + Div<Output = T>
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
 {
     /// Creates a new DSA instance.
     ///
     /// It verifies that the generator `g` is a valid point on the curve `ec`
     /// and computes the group order `n` from `g`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`DSA::try_new`] fails.
     pub fn new(g: Point<T>, ec: Box<dyn EllipticCurve<T>>) -> Self {
+        Self::try_new(g, ec).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Fallible version of [`DSA::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NotOnCurve` if `g` is not on the curve and
+    /// `EccError::OrderNotFound` if its order cannot be computed.
+    pub fn try_new(g: Point<T>, ec: Box<dyn EllipticCurve<T>>) -> Result<Self, EccError> {
         // Check that the generator is valid.
-        assert!(ec.is_valid(&g), "g must be a valid point on the elliptic curve");
+        if !ec.is_valid(&g) {
+            return Err(EccError::NotOnCurve);
+        }
 
-        // Compute the group order by repeatedly adding g until the identity is reached.
-        let n = ec.order(&g).expect("Unable to compute group order from g");
+        // Compute the order of g with baby-step giant-step over the Hasse interval (see
+        // `order::point_order`), about 4q^¼ additions.
+        let n = ec.order(&g)?;
 
-        DSA { g, n, ec }
+        Ok(DSA { g, n, ec })
     }
 
-    pub fn gen_key(&self) -> (i32, Point<T>) {
-        let mut rng_i = rand::rng();
-        let n_i32 = self.n.to_i32().expect("n should be convertible to i32");
-        let priv_gen = rng_i.random_range(1..n_i32);
+    /// Creates a new DSA instance from a generator whose order `n` is already known.
+    ///
+    /// This avoids the baby-step giant-step order computation of [`DSA::new`],
+    /// which is only feasible for fields of up to about 76 bits. It verifies
+    /// that `g` is a finite point of the curve of order exactly n.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`DSA::try_with_order`] fails.
+    pub fn with_order(g: Point<T>, n: T, ec: Box<dyn EllipticCurve<T>>) -> Self {
+        Self::try_with_order(g, n, ec).unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        let point_pub = self.ec.mul(priv_gen, &self.g.clone());
+    /// Fallible version of [`DSA::with_order`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NotOnCurve` if `g` is not on the curve or is the
+    /// identity, and `EccError::InvalidParameters` if the order of `g` is not
+    /// exactly n.
+    pub fn try_with_order(g: Point<T>, n: T, ec: Box<dyn EllipticCurve<T>>) -> Result<Self, EccError> {
+        if n <= T::one() {
+            return Err(EccError::InvalidParameters("n must be greater than 1"));
+        }
+        // n must be the exact order: a multiple of it would make the
+        // inverses modulo n fail or leak information about the nonce.
+        check_generator(ec.as_ref(), &g, &n)?;
+        Ok(DSA { g, n, ec })
+    }
+
+    /// Generates a key pair: a private scalar d uniformly drawn from [1, n) and
+    /// the public point d·g.
+    pub fn gen_key(&self) -> (Scalar<T>, Point<T>) {
+        let priv_gen = Scalar::random(&self.n);
+
+        let point_pub = self.ec.mul(&priv_gen, &self.g);
 
         (priv_gen, point_pub)
     }
+
+    /// Signs `message` with ECDSA (SEC1 §4.1.3, FIPS 186-5 §6.4.1), hashing it
+    /// with SHA-256 and deriving the nonce deterministically (RFC 6979).
+    ///
+    /// Returns the signature (r, s) with r, s in [1, n−1].
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if the private key is not in [1, n−1].
+    pub fn sign(&self, message: &[u8], private_key: &Scalar<T>) -> Result<(T, T), EccError> {
+        self.sign_with::<Sha256>(message, private_key)
+    }
+
+    /// Signs `message` hashed with `D`, using the RFC 6979 nonce derived with
+    /// HMAC-`D` from the private key and the digest.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if the private key is not in [1, n−1].
+    pub fn sign_with<D>(&self, message: &[u8], private_key: &Scalar<T>) -> Result<(T, T), EccError>
+    where
+        D: Digest + BlockSizeUser + Clone,
+    {
+        if !self.in_range(private_key.value()) {
+            return Err(EccError::InvalidParameters("the private key must be in [1, n-1]"));
+        }
+        let digest = D::digest(message);
+        let mut nonces = NonceGenerator::<T, D>::new(&self.n, private_key.value(), &digest);
+        loop {
+            let k = Scalar::modulo(nonces.next_k(), &self.n);
+            match self.sign_digest(&digest, private_key, &k) {
+                // r = 0 or s = 0: continue with the next nonce candidate.
+                Err(EccError::NonInvertible) => continue,
+                result => return result,
+            }
+        }
+    }
+
+    /// Signs an already computed message digest with the nonce `k`.
+    ///
+    /// The digest may come from any hash function; it is truncated to the bit
+    /// length of n. The nonce must be secret, uniformly distributed in [1, n−1]
+    /// and never reused: two signatures sharing a nonce reveal the private key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if the private key or the nonce is
+    /// not in [1, n−1], and `EccError::NonInvertible` if this nonce gives r = 0
+    /// or s = 0, in which case another nonce must be used.
+    pub fn sign_digest(&self, digest: &[u8], private_key: &Scalar<T>, k: &Scalar<T>) -> Result<(T, T), EccError> {
+        if !self.in_range(private_key.value()) {
+            return Err(EccError::InvalidParameters("the private key must be in [1, n-1]"));
+        }
+        if !self.in_range(k.value()) {
+            return Err(EccError::InvalidParameters("the nonce must be in [1, n-1]"));
+        }
+        let n = &self.n;
+        let point = self.ec.mul(k, &self.g);
+        let r = Scalar::modulo(point.x, n);
+        if r.is_zero() {
+            return Err(EccError::NonInvertible);
+        }
+        let e = Scalar::modulo(bits2int(digest, Scalar::new(n.clone()).bits()), n);
+        let d = Scalar::modulo(private_key.value().clone(), n);
+        let k_inv = Scalar::modulo(k.value().clone(), n).inverse()?;
+        let s = k_inv * (e + &r * &d);
+        if s.is_zero() {
+            return Err(EccError::NonInvertible);
+        }
+        Ok((r.value().clone(), s.value().clone()))
+    }
+
+    /// Verifies an ECDSA signature (r, s) of `message` hashed with SHA-256
+    /// (SEC1 §4.1.4, FIPS 186-5 §6.4.2).
+    pub fn verify(&self, message: &[u8], signature: &(T, T), public_key: &Point<T>) -> bool {
+        self.verify_with::<Sha256>(message, signature, public_key)
+    }
+
+    /// Verifies an ECDSA signature (r, s) of `message` hashed with `D`.
+    pub fn verify_with<D>(&self, message: &[u8], signature: &(T, T), public_key: &Point<T>) -> bool
+    where
+        D: Digest,
+    {
+        self.verify_digest(&D::digest(message), signature, public_key)
+    }
+
+    /// Verifies an ECDSA signature (r, s) over an already computed digest.
+    ///
+    /// Rejects r or s outside [1, n−1] and public keys that are not on the
+    /// curve or are the identity.
+    pub fn verify_digest(&self, digest: &[u8], signature: &(T, T), public_key: &Point<T>) -> bool {
+        let (r, s) = signature;
+        if !self.in_range(r) || !self.in_range(s) {
+            return false;
+        }
+        if !self.ec.is_valid(public_key) || self.ec.is_identity(public_key) {
+            return false;
+        }
+        let n = &self.n;
+        let Ok(w) = Scalar::modulo(s.clone(), n).inverse() else {
+            return false;
+        };
+        let e = Scalar::modulo(bits2int(digest, Scalar::new(n.clone()).bits()), n);
+        let u1 = &e * &w;
+        let u2 = Scalar::modulo(r.clone(), n) * w;
+        let point = self.ec.add(&self.ec.mul(&u1, &self.g), &self.ec.mul(&u2, public_key));
+        if self.ec.is_identity(&point) {
+            return false;
+        }
+        point.x.mod_floor(n) == *r
+    }
+
+    /// Returns true if 1 ≤ v ≤ n − 1.
+    fn in_range(&self, v: &T) -> bool {
+        *v > T::zero() && *v < self.n
+    }
 }
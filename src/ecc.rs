@@ -1,7 +1,9 @@
 use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
 use std::ops::{Add, Sub, Mul, Rem};
 use num_integer::Integer;
+use crate::error::EccError;
 use crate::point::Point;
+use crate::scalar::Scalar;
 
 
 
@@ -26,25 +28,62 @@ where
     /// Checks whether a given point is valid on the curve.
     fn is_valid(&self, point: &Point<T>) -> bool;
 
+    /// Returns the identity (point at infinity) in canonical form.
+    fn identity(&self) -> Point<T> {
+        Point::identity()
+    }
+
+    /// Checks whether a point is the identity.
+    ///
+    /// By default only points flagged with z = 0 are; models whose neutral
+    /// element is also an affine point, like (0, 1) on Edwards curves, accept
+    /// that form too.
+    fn is_identity(&self, p: &Point<T>) -> bool {
+        p.is_identity()
+    }
+
     /// Given an x-coordinate (with x < q), returns the two points on the curve,
     /// i.e. (x, y) and (x, -y), if a square root exists.
     ///
     /// # Errors
     ///
-    /// Returns an error if no valid square root is found.
-    fn at(&self, x: T) -> Result<(Point<T>, Point<T>), &'static str>;
+    /// Returns `EccError::NoSquareRoot` if no point has this coordinate.
+    fn at(&self, x: T) -> Result<(Point<T>, Point<T>), EccError>;
 
     /// Adds two points on the curve.
     fn add(&self, p: &Point<T>, q: &Point<T>) -> Point<T>;
 
+    /// Adds two points after checking that both lie on the curve.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NotOnCurve` if either point is invalid, instead of
+    /// producing a meaningless result or panicking.
+    fn checked_add(&self, p: &Point<T>, q: &Point<T>) -> Result<Point<T>, EccError> {
+        if !self.is_valid(p) || !self.is_valid(q) {
+            return Err(EccError::NotOnCurve);
+        }
+        Ok(self.add(p, q))
+    }
+
     /// Doubles a point on the curve.
     fn double(&self, p: &Point<T>) -> Point<T>;
 
-    /// Multiplies a point by a scalar k (i.e. repeated addition).
-    fn mul(&self, n: i32, p: &Point<T>) -> Point<T> ;
+    /// Returns the additive inverse −p of a point.
+    fn neg(&self, p: &Point<T>) -> Point<T>;
+
+    /// Multiplies a point by a scalar n (i.e. repeated addition).
+    ///
+    /// A negative scalar −k yields −(k·p).
+    fn mul(&self, n: &Scalar<T>, p: &Point<T>) -> Point<T>;
 
-    /// Returns the order of the curve (or the group order).
-    fn order(&self, g: &Point<T>) -> Result<T, &'static str>;
+    /// Returns the order of the point g, the smallest n > 0 with n*g = 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NotOnCurve` for an invalid point and
+    /// `EccError::OrderNotFound` if the search gives up.
+    fn order(&self, g: &Point<T>) -> Result<T, EccError>;
 
     fn display(&self) -> String;
 }
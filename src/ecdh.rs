@@ -0,0 +1,277 @@
+use hmac::{Mac, SimpleHmac};
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use sha2::Digest;
+use sha2::digest::core_api::BlockSizeUser;
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::curves;
+use crate::ecc::EllipticCurve;
+use crate::error::EccError;
+use crate::montgomery_curve::MontgomeryCurve;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use crate::utils::Utils;
+
+/// Elliptic curve Diffie–Hellman over any curve model (SEC 1 §3.3.1).
+///
+/// `private` must be reduced modulo the prime order n of the subgroup the
+/// keys live in (see [`Scalar::modulo`]); n is used to check that the peer's
+/// public key lies in that subgroup, which rules out small-subgroup attacks
+/// without multiplying by the cofactor. Returns the x-coordinate of
+/// `private · peer_public`, to be fed to [`hkdf`] or [`x963_kdf`] after
+/// encoding it as a big-endian field element.
+///
+/// # Errors
+///
+/// Returns `EccError::InvalidParameters` if `private` has no group order or is
+/// zero, and `EccError::NotOnCurve` if the peer's point is not on the curve,
+/// is the identity, or is not in the subgroup of order n.
+pub fn shared_secret<T>(
+    curve: &dyn EllipticCurve<T>,
+    private: &Scalar<T>,
+    peer_public: &Point<T>,
+) -> Result<T, EccError>
+where
+    T: Zero
+    + One
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let n = private
+        .order()
+        .ok_or(EccError::InvalidParameters("the private key must be reduced modulo the group order"))?;
+    if private.is_zero() {
+        return Err(EccError::InvalidParameters("the private key must not be zero"));
+    }
+    if !curve.is_valid(peer_public) {
+        return Err(EccError::NotOnCurve);
+    }
+    if curve.is_identity(peer_public) || !curve.is_identity(&curve.mul(&Scalar::new(n.clone()), peer_public)) {
+        return Err(EccError::NotOnCurve);
+    }
+    let shared = curve.mul(private, peer_public);
+    if curve.is_identity(&shared) {
+        return Err(EccError::NotOnCurve);
+    }
+    Ok(shared.x)
+}
+
+/// HKDF (RFC 5869) with HMAC over `D`: extracts a pseudorandom key from the
+/// shared secret and `salt`, then expands it with `info` to `len` bytes.
+///
+/// # Errors
+///
+/// Returns `EccError::InvalidParameters` if `len` exceeds 255 hash lengths.
+pub fn hkdf<D>(secret: &[u8], salt: &[u8], info: &[u8], len: usize) -> Result<Vec<u8>, EccError>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    let hash_len = <D as Digest>::output_size();
+    if len > 255 * hash_len {
+        return Err(EccError::InvalidParameters("HKDF output is at most 255 hash lengths"));
+    }
+    // An absent salt is a string of hash_len zeros.
+    let zeros = vec![0u8; hash_len];
+    let salt = if salt.is_empty() { &zeros[..] } else { salt };
+    let prk = hmac::<D>(salt, &[secret]);
+    let mut okm = Vec::with_capacity(len);
+    let mut block = Vec::new();
+    for counter in 1..=len.div_ceil(hash_len) as u8 {
+        block = hmac::<D>(&prk, &[&block, info, &[counter]]);
+        okm.extend_from_slice(&block);
+    }
+    okm.truncate(len);
+    Ok(okm)
+}
+
+/// The ANSI X9.63 key derivation function (SEC 1 §3.6.1): the concatenation
+/// of D(secret || counter || shared_info) for a 32-bit big-endian counter
+/// starting at 1, truncated to `len` bytes.
+///
+/// # Errors
+///
+/// Returns `EccError::InvalidParameters` if `len` needs more than 2^32 − 1 blocks.
+pub fn x963_kdf<D>(secret: &[u8], shared_info: &[u8], len: usize) -> Result<Vec<u8>, EccError>
+where
+    D: Digest,
+{
+    let blocks = len.div_ceil(<D as Digest>::output_size());
+    let blocks = u32::try_from(blocks)
+        .map_err(|_| EccError::InvalidParameters("X9.63 output is at most 2^32 − 1 hash lengths"))?;
+    let mut key = Vec::with_capacity(len);
+    for counter in 1..=blocks {
+        let mut hasher = D::new();
+        hasher.update(secret);
+        hasher.update(counter.to_be_bytes());
+        hasher.update(shared_info);
+        key.extend_from_slice(&hasher.finalize());
+    }
+    key.truncate(len);
+    Ok(key)
+}
+
+/// HMAC_key(parts concatenated).
+fn hmac<D>(key: &[u8], parts: &[&[u8]]) -> Vec<u8>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    let mut mac = <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// X25519 and X448 Diffie–Hellman (RFC 7748) on a Montgomery curve.
+///
+/// Scalars and u-coordinates are little-endian byte strings of 32 bytes for
+/// X25519 and 56 bytes for X448. `T` must hold integers of at least 512 bits
+/// (X25519) or 896 bits (X448), e.g. `BigInt`.
+pub struct Xdh<T> {
+    curve: MontgomeryCurve<T>,
+    /// Length of scalars and u-coordinates in bytes.
+    len: usize,
+    /// Bits of the field prime; for X25519 the unused top bit of u is masked.
+    bits: u32,
+    /// Number of low scalar bits cleared to clear the cofactor (3 or 2).
+    cofactor_bits: u32,
+    /// u-coordinate of the base point.
+    base: T,
+}
+
+impl<T> Xdh<T>
+where
+    T: Zero
+    + One
+    + std::fmt::Display
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    /// X25519 on Curve25519 ([`curves::curve25519`]): v² = u³ + 486662*u² + u
+    /// over 2^255 − 19, base u = 9.
+    pub fn x25519() -> Self {
+        let curve25519 = curves::curve25519();
+        Xdh {
+            curve: curve25519.curve,
+            len: 32,
+            bits: 255,
+            cofactor_bits: 3,
+            base: curve25519.generator.x,
+        }
+    }
+
+    /// X448 on Curve448 ([`curves::curve448`]): v² = u³ + 156326*u² + u over
+    /// 2^448 − 2^224 − 1, base u = 5.
+    pub fn x448() -> Self {
+        let curve448 = curves::curve448();
+        Xdh {
+            curve: curve448.curve,
+            len: 56,
+            bits: 448,
+            cofactor_bits: 2,
+            base: curve448.generator.x,
+        }
+    }
+
+    /// Returns the underlying Montgomery curve.
+    pub fn curve(&self) -> &MontgomeryCurve<T> {
+        &self.curve
+    }
+
+    /// Length in bytes of scalars, public keys and shared secrets.
+    pub fn key_len(&self) -> usize {
+        self.len
+    }
+
+    /// The X25519 / X448 function: multiplies the u-coordinate `u` by the
+    /// clamped scalar `k` and returns the encoded u-coordinate of the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` if `k` or `u` has the wrong length.
+    pub fn scalar_mult(&self, k: &[u8], u: &[u8]) -> Result<Vec<u8>, EccError> {
+        let k = self.decode_scalar(k)?;
+        let u = self.decode_u(u)?;
+        Ok(Utils::to_bytes_le(&self.curve.ladder(&k, &u), self.len))
+    }
+
+    /// Computes the public key, i.e. the secret scalar times the base point.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` if the secret has the wrong length.
+    pub fn public_key(&self, secret: &[u8]) -> Result<Vec<u8>, EccError> {
+        self.scalar_mult(secret, &Utils::to_bytes_le(&self.base, self.len))
+    }
+
+    /// Computes the shared secret with a peer's public key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` on a wrong length and `EccError::NotOnCurve`
+    /// if the result is all zeros, which happens when the peer sent a point of
+    /// small order (RFC 7748 §6).
+    pub fn shared_secret(&self, secret: &[u8], peer_public: &[u8]) -> Result<Vec<u8>, EccError> {
+        let shared = self.scalar_mult(secret, peer_public)?;
+        if shared.iter().all(|byte| *byte == 0) {
+            return Err(EccError::NotOnCurve);
+        }
+        Ok(shared)
+    }
+
+    /// decodeScalar25519 / decodeScalar448: clears the cofactor bits, clears
+    /// the bits above the top one and sets the top bit.
+    fn decode_scalar(&self, k: &[u8]) -> Result<T, EccError> {
+        if k.len() != self.len {
+            return Err(EccError::Encoding("scalar has the wrong length"));
+        }
+        let mut k = k.to_vec();
+        k[0] &= !((1u8 << self.cofactor_bits) - 1);
+        let top = self.bits - 1;
+        let last = self.len - 1;
+        // Bit `top` is bit 6 of the last byte for X25519 and bit 7 for X448.
+        k[last] &= ((1u16 << (top % 8 + 1)) - 1) as u8;
+        k[last] |= 1 << (top % 8);
+        Ok(Utils::from_bytes_le(&k))
+    }
+
+    /// decodeUCoordinate: masks the bits beyond the field size; non-canonical
+    /// values are accepted and reduced by the ladder.
+    fn decode_u(&self, u: &[u8]) -> Result<T, EccError> {
+        if u.len() != self.len {
+            return Err(EccError::Encoding("u-coordinate has the wrong length"));
+        }
+        let mut u = u.to_vec();
+        if !self.bits.is_multiple_of(8) {
+            let last = self.len - 1;
+            u[last] &= (1u8 << (self.bits % 8)) - 1;
+        }
+        Ok(Utils::from_bytes_le(&u))
+    }
+}
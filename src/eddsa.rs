@@ -0,0 +1,232 @@
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use sha2::{Digest, Sha512};
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::curves;
+use crate::ecc::EllipticCurve;
+use crate::error::EccError;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use crate::twisted_curve::TwistedCurve;
+use crate::utils::Utils;
+
+/// Prefix of dom2 (RFC 8032 §5.1), used by Ed25519ctx and Ed25519ph only.
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+
+/// Ed25519 signatures (RFC 8032 §5.1) on the twisted Edwards curve
+///     −x² + y² = 1 + d*x²*y²   (mod 2^255 − 19),  d = −121665/121666.
+///
+/// Secret keys are 32 random bytes, public keys are 32-byte encoded points and
+/// signatures are 64 bytes R || S. Besides pure Ed25519, the Ed25519ctx and
+/// Ed25519ph (pre-hashed with SHA-512) variants bind a context string to the
+/// signature. `T` must hold integers of at least 512 bits, e.g. `BigInt`.
+pub struct Ed25519<T> {
+    curve: TwistedCurve<T>,
+    base: Point<T>,
+}
+
+impl<T> Ed25519<T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    /// Sets up edwards25519 ([`curves::ed25519`]) with the base point
+    /// B = (x, 4/5), x even, of prime order L = 2^252 + 27742317777372353535851937790883648493.
+    pub fn new() -> Self {
+        let ed25519 = curves::ed25519();
+        Ed25519 { curve: ed25519.curve, base: ed25519.generator }
+    }
+
+    /// Returns the underlying twisted Edwards curve.
+    pub fn curve(&self) -> &TwistedCurve<T> {
+        &self.curve
+    }
+
+    /// Returns the base point B.
+    pub fn base(&self) -> &Point<T> {
+        &self.base
+    }
+
+    /// Returns the group order L.
+    pub fn order(&self) -> &T {
+        &self.curve.order
+    }
+
+    /// Derives the public key A = s·B from a 32-byte secret key.
+    pub fn public_key(&self, secret: &[u8; 32]) -> [u8; 32] {
+        let (s, _) = self.expand(secret);
+        self.encode(&self.curve.mul(&Scalar::new(s), &self.base))
+    }
+
+    /// Signs `message` with pure Ed25519.
+    pub fn sign(&self, secret: &[u8; 32], message: &[u8]) -> [u8; 64] {
+        self.sign_with_dom(secret, message, &[])
+    }
+
+    /// Verifies a pure Ed25519 signature.
+    pub fn verify(&self, public: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+        self.verify_with_dom(public, message, signature, &[])
+    }
+
+    /// Signs `message` with Ed25519ctx under a context of 1 to 255 bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if the context is empty or too long.
+    pub fn sign_ctx(&self, secret: &[u8; 32], message: &[u8], context: &[u8]) -> Result<[u8; 64], EccError> {
+        if context.is_empty() {
+            return Err(EccError::InvalidParameters("Ed25519ctx requires a non-empty context"));
+        }
+        Ok(self.sign_with_dom(secret, message, &dom2(0, context)?))
+    }
+
+    /// Verifies an Ed25519ctx signature; fails for an empty or too long context.
+    pub fn verify_ctx(&self, public: &[u8; 32], message: &[u8], signature: &[u8; 64], context: &[u8]) -> bool {
+        match dom2(0, context) {
+            Ok(dom) if !context.is_empty() => self.verify_with_dom(public, message, signature, &dom),
+            _ => false,
+        }
+    }
+
+    /// Signs the SHA-512 digest of `message` with Ed25519ph under a context of
+    /// at most 255 bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if the context is too long.
+    pub fn sign_ph(&self, secret: &[u8; 32], message: &[u8], context: &[u8]) -> Result<[u8; 64], EccError> {
+        let dom = dom2(1, context)?;
+        Ok(self.sign_with_dom(secret, &Sha512::digest(message), &dom))
+    }
+
+    /// Verifies an Ed25519ph signature; fails for a too long context.
+    pub fn verify_ph(&self, public: &[u8; 32], message: &[u8], signature: &[u8; 64], context: &[u8]) -> bool {
+        match dom2(1, context) {
+            Ok(dom) => self.verify_with_dom(public, &Sha512::digest(message), signature, &dom),
+            Err(_) => false,
+        }
+    }
+
+    /// Key expansion: h = SHA-512(secret), the secret scalar s is the clamped
+    /// first half of h and the second half is the nonce prefix.
+    fn expand(&self, secret: &[u8; 32]) -> (T, [u8; 32]) {
+        let h = Sha512::digest(secret);
+        let mut lower = [0u8; 32];
+        lower.copy_from_slice(&h[..32]);
+        // Clear the cofactor bits, clear bit 255 and set bit 254.
+        lower[0] &= 248;
+        lower[31] &= 127;
+        lower[31] |= 64;
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&h[32..]);
+        (Utils::from_bytes_le(&lower), prefix)
+    }
+
+    /// SHA-512 of the concatenated parts, as an integer modulo L.
+    fn hash_to_scalar(&self, parts: &[&[u8]]) -> Scalar<T> {
+        let mut hasher = Sha512::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        Scalar::modulo(Utils::from_bytes_le(&hasher.finalize()), self.order())
+    }
+
+    /// Signature generation (RFC 8032 §5.1.6), with `dom` empty for pure Ed25519.
+    fn sign_with_dom(&self, secret: &[u8; 32], message: &[u8], dom: &[u8]) -> [u8; 64] {
+        let (s, prefix) = self.expand(secret);
+        let public = self.encode(&self.curve.mul(&Scalar::new(s.clone()), &self.base));
+        let r = self.hash_to_scalar(&[dom, &prefix, message]);
+        let big_r = self.encode(&self.curve.mul(&r, &self.base));
+        let k = self.hash_to_scalar(&[dom, &big_r, &public, message]);
+        let big_s = r + k * Scalar::modulo(s, self.order());
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&big_r);
+        signature[32..].copy_from_slice(&Utils::to_bytes_le(big_s.value(), 32));
+        signature
+    }
+
+    /// Signature verification (RFC 8032 §5.1.7) with the cofactored equation
+    /// [8][S]B = [8]R + [8][k]A.
+    fn verify_with_dom(&self, public: &[u8; 32], message: &[u8], signature: &[u8; 64], dom: &[u8]) -> bool {
+        let (Ok(a), Ok(r)) = (self.decode(public), self.decode(&signature[..32])) else {
+            return false;
+        };
+        let s: T = Utils::from_bytes_le(&signature[32..]);
+        if s >= *self.order() {
+            return false;
+        }
+        let k = self.hash_to_scalar(&[dom, &signature[..32], public, message]);
+        let eight = Scalar::from(8);
+        let left = self.curve.mul(&(&eight * &Scalar::new(s)), &self.base);
+        // k is reduced modulo L, so the cofactor is applied to R + [k]A as a whole.
+        let right = self.curve.mul(&eight, &self.curve.add(&r, &self.curve.mul(&k, &a)));
+        left == right
+    }
+
+    /// Encodes a point as the 255-bit little-endian y with the parity of x in the top bit.
+    fn encode(&self, p: &Point<T>) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&self.curve.encode_point(p));
+        out
+    }
+
+    /// Decodes a 32-byte point, rejecting y ≥ q and x = 0 with the sign bit set.
+    fn decode(&self, bytes: &[u8]) -> Result<Point<T>, EccError> {
+        self.curve.decode_point(bytes)
+    }
+}
+
+impl<T> Default for Ed25519<T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// dom2(phflag, context) = "SigEd25519 no Ed25519 collisions" || phflag || len(context) || context.
+fn dom2(phflag: u8, context: &[u8]) -> Result<Vec<u8>, EccError> {
+    let len = u8::try_from(context.len())
+        .map_err(|_| EccError::InvalidParameters("the context is at most 255 bytes"))?;
+    let mut dom = DOM2_PREFIX.to_vec();
+    dom.push(phflag);
+    dom.push(len);
+    dom.extend_from_slice(context);
+    Ok(dom)
+}
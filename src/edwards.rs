@@ -0,0 +1,161 @@
+use num_traits::{FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use std::ops::{BitAnd, Shl, Shr};
+use std::sync::Arc;
+use crate::error::EccError;
+use crate::field::{Fp, PrimeField};
+use crate::point::Point;
+use crate::utils::Utils;
+
+// Helpers shared by `EdwardsCurve` and `TwistedCurve`, which both describe the
+// curve a*x² + y² = 1 + d*x²*y² over F_q and only differ in how they are built.
+
+/// Builds the point (x, y), returning the neutral element (0, 1) as the identity.
+pub(crate) fn point<T>(x: Fp<T>, y: Fp<T>) -> Point<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    if x.is_zero() && y.is_one() {
+        return Point::identity();
+    }
+    Point::new(x.value(), y.value())
+}
+
+/// Checks that the unified addition law is complete, i.e. that a is a square
+/// and d is not (Bernstein–Lange), so that no sum reaches Z = 0.
+pub(crate) fn check_complete<T>(field: &Arc<PrimeField<T>>, a: &T, d: &T) -> Result<(), EccError>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    if field.element(a.clone()).legendre() != 1 || field.element(d.clone()).legendre() != -1 {
+        return Err(EccError::InvalidParameters("the addition law is incomplete: a must be a square and d a non-square"));
+    }
+    Ok(())
+}
+
+/// Recovers the even x with a*x² + y² = 1 + d*x²*y², i.e. a square root of
+/// (y² − 1) / (d*y² − a).
+pub(crate) fn xrecover<T>(field: &Arc<PrimeField<T>>, a: &T, d: &T, y: T) -> Result<T, EccError>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let y2 = field.element(y).square();
+    let numerator = &y2 - field.one();
+    let denominator = field.element(d.clone()) * y2 - field.element(a.clone());
+    let xx = numerator * denominator.inverse()?;
+    let mut x = xx.sqrt().ok_or(EccError::NoSquareRoot)?;
+    // Ensure x is “even”. Here, we check if x mod 2 is nonzero.
+    if x.value().is_odd() {
+        x = -x;
+    }
+    Ok(x.value())
+}
+
+/// Iterates over every affine point of the curve, the identity first, by
+/// recovering x from each y.
+pub(crate) fn points<'a, T>(field: &'a Arc<PrimeField<T>>, a: &'a T, d: &'a T) -> impl Iterator<Item = Point<T>> + 'a
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let finite = Utils::residues(field.modulus()).flat_map(move |y| {
+        let Ok(x) = xrecover(field, a, d, y.clone()) else {
+            return vec![];
+        };
+        let (x, y) = (field.element(x), field.element(y));
+        if x.is_zero() {
+            return vec![point(x, y)];
+        }
+        vec![point(x.clone(), y.clone()), point(-x, y)]
+    });
+    std::iter::once(Point::identity()).chain(finite.filter(|p| !p.is_identity()))
+}
+
+/// Length in bytes of an encoded point, ⌈(log₂(q) + 1) / 8⌉.
+pub(crate) fn encoded_len<T>(field: &Arc<PrimeField<T>>) -> usize
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    (Utils::bit_length(field.modulus()) as usize + 1).div_ceil(8)
+}
+
+/// Encodes a point as in RFC 8032 §5.1.2 / §5.2.2.
+pub(crate) fn encode_point<T>(field: &Arc<PrimeField<T>>, p: &Point<T>) -> Vec<u8>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + ToPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let p = if p.is_identity() { Point::new(T::zero(), T::one()) } else { p.clone() };
+    let (x, y) = (field.element(p.x).value(), field.element(p.y).value());
+    let mut out = Utils::to_bytes_le(&y, encoded_len(field));
+    if x.is_odd() {
+        *out.last_mut().expect("encodings are not empty") |= 0x80;
+    }
+    out
+}
+
+/// Decodes a point encoded by [`encode_point`] (RFC 8032 §5.1.3 / §5.2.3).
+pub(crate) fn decode_point<T>(field: &Arc<PrimeField<T>>, a: &T, d: &T, bytes: &[u8]) -> Result<Point<T>, EccError>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + ToPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    if bytes.len() != encoded_len(field) {
+        return Err(EccError::Encoding("point encoding has the wrong length"));
+    }
+    let mut y_bytes = bytes.to_vec();
+    let last = y_bytes.len() - 1;
+    let sign = y_bytes[last] >> 7 == 1;
+    y_bytes[last] &= 0x7f;
+    let y: T = Utils::from_bytes_le(&y_bytes);
+    if &y >= field.modulus() {
+        return Err(EccError::Encoding("non-canonical y-coordinate"));
+    }
+    let x = field.element(xrecover(field, a, d, y.clone()).map_err(|_| EccError::NotOnCurve)?);
+    if x.is_zero() && sign {
+        return Err(EccError::Encoding("x = 0 with the sign bit set"));
+    }
+    let x = if x.value().is_odd() == sign { x } else { -x };
+    Ok(point(x, field.element(y)))
+}
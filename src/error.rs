@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// Errors returned by the fallible operations of the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EccError {
+    /// A point does not satisfy the curve equation.
+    NotOnCurve,
+    /// An element has no multiplicative inverse (zero, or not coprime to the modulus).
+    NonInvertible,
+    /// A field element is not a quadratic residue, so no point has this coordinate.
+    NoSquareRoot,
+    /// Curve or field parameters are rejected; the payload says which check failed.
+    InvalidParameters(&'static str),
+    /// The order of a point could not be determined.
+    OrderNotFound,
+    /// A byte string or number is not a valid encoding.
+    Encoding(&'static str),
+    /// The operation is not available for this curve model.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for EccError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EccError::NotOnCurve => write!(f, "point is not on the curve"),
+            EccError::NonInvertible => write!(f, "element is not invertible"),
+            EccError::NoSquareRoot => write!(f, "no square root exists"),
+            EccError::InvalidParameters(why) => write!(f, "invalid parameters: {}", why),
+            EccError::OrderNotFound => write!(f, "point order not found"),
+            EccError::Encoding(why) => write!(f, "invalid encoding: {}", why),
+            EccError::Unsupported(what) => write!(f, "unsupported operation: {}", what),
+        }
+    }
+}
+
+impl std::error::Error for EccError {}
@@ -0,0 +1,93 @@
+use num_traits::FromPrimitive;
+use num_integer::Integer;
+use std::ops::{BitAnd, Shl, Shr};
+use crate::error::EccError;
+use crate::field::Fp;
+
+/// A point on a (twisted) Edwards curve a*x² + y² = 1 + d*x²*y² in extended
+/// coordinates (X : Y : Z : T), standing for the affine point (X/Z, Y/Z) with
+/// the auxiliary coordinate T = X*Y/Z.
+///
+/// See Hisil, Wong, Carter and Dawson, "Twisted Edwards Curves Revisited"
+/// (ASIACRYPT 2008). The identity is (0 : 1 : 1 : 0). Since the same point has
+/// many representations, compare points after converting them back to affine.
+#[derive(Clone)]
+pub struct ExtendedPoint<T> {
+    pub x: Fp<T>,
+    pub y: Fp<T>,
+    pub z: Fp<T>,
+    pub t: Fp<T>,
+}
+
+impl<T> ExtendedPoint<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    /// Lifts the affine point (x, y) to (x : y : 1 : x*y).
+    pub fn from_affine(x: Fp<T>, y: Fp<T>) -> Self {
+        let t = &x * &y;
+        let z = x.field().one();
+        ExtendedPoint { x, y, z, t }
+    }
+
+    /// Returns the affine coordinates (X/Z, Y/Z) using a single inversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Z = 0, which only happens after an exceptional
+    /// addition on a curve whose addition law is not complete.
+    pub fn to_affine(&self) -> Result<(Fp<T>, Fp<T>), EccError> {
+        let z_inv = self.z.inverse()?;
+        Ok((&self.x * &z_inv, &self.y * z_inv))
+    }
+
+    /// Unified addition "add-2008-hwcd"; complete when a is a square and d is not.
+    ///
+    ///   A = X1*X2, B = Y1*Y2, C = d*T1*T2, D = Z1*Z2,
+    ///   E = (X1+Y1)*(X2+Y2) − A − B, F = D − C, G = D + C, H = B − a*A,
+    ///   X3 = E*F, Y3 = G*H, T3 = E*H, Z3 = F*G.
+    pub fn add(&self, other: &Self, a: &Fp<T>, d: &Fp<T>) -> Self {
+        let aa = &self.x * &other.x;
+        let bb = &self.y * &other.y;
+        let cc = d * &self.t * &other.t;
+        let dd = &self.z * &other.z;
+        let e = (&self.x + &self.y) * (&other.x + &other.y) - &aa - &bb;
+        let f = &dd - &cc;
+        let g = dd + cc;
+        let h = bb - a * aa;
+        ExtendedPoint {
+            x: &e * &f,
+            y: &g * &h,
+            t: e * h,
+            z: f * g,
+        }
+    }
+
+    /// Dedicated doubling "dbl-2008-hwcd", which does not need d or T.
+    ///
+    ///   A = X1², B = Y1², C = 2*Z1², D = a*A, E = (X1+Y1)² − A − B,
+    ///   G = D + B, F = G − C, H = D − B,
+    ///   X3 = E*F, Y3 = G*H, T3 = E*H, Z3 = F*G.
+    pub fn double(&self, a: &Fp<T>) -> Self {
+        let aa = self.x.square();
+        let bb = self.y.square();
+        let cc = self.z.square().double();
+        let dd = a * &aa;
+        let e = (&self.x + &self.y).square() - aa - &bb;
+        let g = &dd + &bb;
+        let f = &g - cc;
+        let h = dd - bb;
+        ExtendedPoint {
+            x: &e * &f,
+            y: &g * &h,
+            t: e * h,
+            z: f * g,
+        }
+    }
+}
@@ -0,0 +1,738 @@
+use num_traits::FromPrimitive;
+use num_integer::Integer;
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Div, Neg, Shl, Shr, BitAnd};
+use std::sync::Arc;
+use crate::error::EccError;
+use crate::utils::Utils;
+
+/// Strategy used by a [`PrimeField`] to reduce products modulo q.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reduction {
+    /// Plain integer division (`mod_floor`) after every product.
+    #[default]
+    Generic,
+    /// Montgomery multiplication (REDC); elements are stored as a·R mod q with R = 2^k.
+    /// Requires an odd modulus.
+    ///
+    /// REDC is written with masks and shifts on `T` rather than on fixed-size
+    /// limbs, so with `BigInt` it is slower than `Generic` (about 0.6x per
+    /// P-256 multiplication in `benches/field_bench.rs`).
+    Montgomery,
+    /// Barrett reduction with a precomputed μ = ⌊4^k / q⌋.
+    ///
+    /// Like `Montgomery`, it works on whole `T` values and is slower than
+    /// `Generic` with `BigInt` (about 0.5x per P-256 multiplication).
+    Barrett,
+    /// Division-free folding for pseudo-Mersenne primes q = 2^k − c with a small c
+    /// (Curve25519, secp256k1, P-521): x = h·2^k + l ≡ l + h·c.
+    ///
+    /// The folding works on whole `T` values, not machine words, and with
+    /// `BigInt` it runs at about 0.8x of `Generic` on 2^255 − 19.
+    PseudoMersenne,
+    /// Shift-and-add folding for Solinas (generalized Mersenne) primes
+    /// q = 2^k − Σ ±2^e with few terms (P-192, P-224, P-256, P-384, Curve448).
+    ///
+    /// Each term costs a big-integer shift and addition, so with `BigInt` this
+    /// is far slower than `Generic` (over 10x per P-256 multiplication).
+    Solinas,
+}
+
+/// Largest c (in bits) for which q = 2^k − c is treated as pseudo-Mersenne.
+const PSEUDO_MERSENNE_MAX_C_BITS: u32 = 64;
+/// Largest number of signed power-of-two terms in 2^k − q for a Solinas prime.
+const SOLINAS_MAX_TERMS: usize = 8;
+/// Fields smaller than this are left on the generic path by [`Reduction::detect`].
+const SPECIAL_FORM_MIN_BITS: u32 = 128;
+
+impl Reduction {
+    /// Picks the special-form reduction that matches the shape of `q`, if any.
+    ///
+    /// Returns `PseudoMersenne` when q = 2^k − c with c below 2^64, `Solinas`
+    /// when 2^k − q is a short signed sum of powers of two, and `Generic`
+    /// otherwise (including all toy-sized moduli).
+    ///
+    /// Curves do not call this: they use `Generic`, which is faster with
+    /// `BigInt`. Pass the result to `with_reduction` to opt in.
+    pub fn detect<T>(q: &T) -> Reduction
+    where
+        T: Clone
+        + Integer
+        + FromPrimitive
+        + Shl<u32, Output = T>
+        + Shr<u32, Output = T>,
+    {
+        if *q <= T::one() {
+            return Reduction::Generic;
+        }
+        let k = Utils::bit_length(q);
+        if k < SPECIAL_FORM_MIN_BITS {
+            return Reduction::Generic;
+        }
+        let c = (T::one() << k) - q.clone();
+        if Utils::bit_length(&c) <= PSEUDO_MERSENNE_MAX_C_BITS {
+            return Reduction::PseudoMersenne;
+        }
+        match solinas_terms(&c) {
+            Some(terms) if solinas_applicable(k, &terms) => Reduction::Solinas,
+            _ => Reduction::Generic,
+        }
+    }
+}
+
+/// Precomputed constants of the selected [`Reduction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Reducer<T> {
+    Generic,
+    Montgomery {
+        /// k, with R = 2^k > q.
+        k: u32,
+        /// R − 1, used to take values modulo R.
+        mask: T,
+        /// −q⁻¹ mod R.
+        q_neg_inv: T,
+        /// R² mod q, used to enter the Montgomery domain.
+        r2: T,
+    },
+    Barrett {
+        /// Bit length of q.
+        k: u32,
+        /// ⌊2^(2k) / q⌋.
+        mu: T,
+    },
+    PseudoMersenne {
+        /// Bit length of q.
+        k: u32,
+        /// 2^k − 1.
+        mask: T,
+        /// c = 2^k − q.
+        c: T,
+    },
+    Solinas {
+        /// Bit length of q.
+        k: u32,
+        /// 2^k − 1.
+        mask: T,
+        /// Exponents e with a +2^e term in 2^k − q.
+        plus: Vec<u32>,
+        /// Exponents e with a −2^e term in 2^k − q.
+        minus: Vec<u32>,
+    },
+}
+
+/// A prime field F_q.
+///
+/// The field stores its modulus together with the constants of the chosen
+/// [`Reduction`]; elements are created through [`PrimeField::element`] and keep
+/// a shared handle to the field they live in, so that every arithmetic
+/// operation knows which modulus to reduce by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrimeField<T> {
+    modulus: T,
+    reducer: Reducer<T>,
+    /// Internal representation of 1 (R mod q in the Montgomery domain).
+    one: T,
+}
+
+impl<T> PrimeField<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    /// Creates the field of integers modulo the prime `q` with generic reduction.
+    pub fn new(q: T) -> Arc<Self> {
+        Self::with_reduction(q, Reduction::Generic)
+    }
+
+    /// Creates the field of integers modulo the prime `q` using the given reduction strategy.
+    pub fn with_reduction(q: T, reduction: Reduction) -> Arc<Self> {
+        assert!(q > T::one(), "the field modulus must be greater than 1");
+        let k = Utils::bit_length(&q);
+        let reducer = match reduction {
+            Reduction::Generic => Reducer::Generic,
+            Reduction::Montgomery => {
+                assert!(q.is_odd(), "Montgomery reduction requires an odd modulus");
+                let r = T::one() << k;
+                let q_inv = Utils::mod_inv(q.clone(), r.clone()).expect("q is odd, so it is invertible modulo 2^k");
+                let r2 = (r.clone() * r.clone()).mod_floor(&q);
+                Reducer::Montgomery {
+                    k,
+                    mask: r.clone() - T::one(),
+                    q_neg_inv: r - q_inv,
+                    r2,
+                }
+            }
+            Reduction::Barrett => Reducer::Barrett {
+                k,
+                mu: (T::one() << (2 * k)) / q.clone(),
+            },
+            Reduction::PseudoMersenne => {
+                let c = (T::one() << k) - q.clone();
+                assert!(
+                    Utils::bit_length(&c) <= PSEUDO_MERSENNE_MAX_C_BITS && Utils::bit_length(&c) < k / 2,
+                    "pseudo-Mersenne reduction requires q = 2^k - c with a small c"
+                );
+                Reducer::PseudoMersenne { k, mask: (T::one() << k) - T::one(), c }
+            }
+            Reduction::Solinas => {
+                let c = (T::one() << k) - q.clone();
+                let terms = solinas_terms(&c)
+                    .filter(|terms| solinas_applicable(k, terms))
+                    .expect("Solinas reduction requires q = 2^k - sum of a few powers of two");
+                let plus = terms.iter().filter(|(_, sign)| *sign > 0).map(|(e, _)| *e).collect();
+                let minus = terms.iter().filter(|(_, sign)| *sign < 0).map(|(e, _)| *e).collect();
+                Reducer::Solinas { k, mask: (T::one() << k) - T::one(), plus, minus }
+            }
+        };
+        let mut field = PrimeField { modulus: q, reducer, one: T::zero() };
+        field.one = field.encode(T::one());
+        Arc::new(field)
+    }
+
+    /// Returns the modulus q.
+    pub fn modulus(&self) -> &T {
+        &self.modulus
+    }
+
+    /// Returns the reduction strategy used by this field.
+    pub fn reduction(&self) -> Reduction {
+        match self.reducer {
+            Reducer::Generic => Reduction::Generic,
+            Reducer::Montgomery { .. } => Reduction::Montgomery,
+            Reducer::Barrett { .. } => Reduction::Barrett,
+            Reducer::PseudoMersenne { .. } => Reduction::PseudoMersenne,
+            Reducer::Solinas { .. } => Reduction::Solinas,
+        }
+    }
+
+    /// Creates a field element from an integer, reducing it into [0, q).
+    pub fn element(self: &Arc<Self>, value: T) -> Fp<T> {
+        Fp {
+            value: self.encode(value),
+            field: Arc::clone(self),
+        }
+    }
+
+    /// The additive identity.
+    pub fn zero(self: &Arc<Self>) -> Fp<T> {
+        Fp { value: T::zero(), field: Arc::clone(self) }
+    }
+
+    /// The multiplicative identity.
+    pub fn one(self: &Arc<Self>) -> Fp<T> {
+        Fp { value: self.one.clone(), field: Arc::clone(self) }
+    }
+
+    /// Maps an arbitrary (possibly negative) integer to its internal representation.
+    fn encode(&self, value: T) -> T {
+        let value = value.mod_floor(&self.modulus);
+        match &self.reducer {
+            Reducer::Montgomery { r2, .. } => self.mul(&value, r2),
+            _ => value,
+        }
+    }
+
+    /// Maps an internal representation back to the canonical integer in [0, q).
+    fn decode(&self, value: &T) -> T {
+        match &self.reducer {
+            Reducer::Montgomery { .. } => self.redc(value.clone()),
+            _ => value.clone(),
+        }
+    }
+
+    fn add(&self, a: &T, b: &T) -> T {
+        let sum = a.clone() + b.clone();
+        if sum >= self.modulus {
+            sum - self.modulus.clone()
+        } else {
+            sum
+        }
+    }
+
+    fn sub(&self, a: &T, b: &T) -> T {
+        if a >= b {
+            a.clone() - b.clone()
+        } else {
+            a.clone() + self.modulus.clone() - b.clone()
+        }
+    }
+
+    fn mul(&self, a: &T, b: &T) -> T {
+        let product = a.clone() * b.clone();
+        match &self.reducer {
+            Reducer::Generic => product.mod_floor(&self.modulus),
+            Reducer::Montgomery { .. } => self.redc(product),
+            Reducer::Barrett { .. } => self.barrett(product),
+            Reducer::PseudoMersenne { .. } | Reducer::Solinas { .. } => self.fold(product),
+        }
+    }
+
+    /// Montgomery reduction: returns t·R⁻¹ mod q for 0 ≤ t < q·R.
+    fn redc(&self, t: T) -> T {
+        let Reducer::Montgomery { k, mask, q_neg_inv, .. } = &self.reducer else {
+            unreachable!("redc called on a field without Montgomery reduction");
+        };
+        let m = ((t.clone() & mask.clone()) * q_neg_inv.clone()) & mask.clone();
+        let u = (t + m * self.modulus.clone()) >> *k;
+        if u >= self.modulus {
+            u - self.modulus.clone()
+        } else {
+            u
+        }
+    }
+
+    /// Barrett reduction: returns x mod q for 0 ≤ x < q².
+    fn barrett(&self, x: T) -> T {
+        let Reducer::Barrett { k, mu } = &self.reducer else {
+            unreachable!("barrett called on a field without Barrett reduction");
+        };
+        let estimate = ((x.clone() >> (*k - 1)) * mu.clone()) >> (*k + 1);
+        let mut r = x - estimate * self.modulus.clone();
+        // The estimate is off by at most two multiples of q.
+        while r >= self.modulus {
+            r = r - self.modulus.clone();
+        }
+        r
+    }
+}
+
+impl<T> PrimeField<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    /// Special-form reduction of a non-negative x, without division.
+    ///
+    /// Writes x = h·2^k + l and replaces it by l + h·(2^k − q), which is congruent
+    /// modulo q, until x < 2^k; a final conditional subtraction brings it below q.
+    /// For pseudo-Mersenne primes h·c is a single small multiplication, for Solinas
+    /// primes it is a handful of shifts and additions.
+    fn fold(&self, mut x: T) -> T {
+        let (k, mask) = match &self.reducer {
+            Reducer::PseudoMersenne { k, mask, .. } | Reducer::Solinas { k, mask, .. } => (*k, mask),
+            _ => unreachable!("fold called on a field without special-form reduction"),
+        };
+        loop {
+            let high = x.clone() >> k;
+            if high.is_zero() {
+                break;
+            }
+            let low = x & mask.clone();
+            x = match &self.reducer {
+                Reducer::PseudoMersenne { c, .. } => low + high * c.clone(),
+                Reducer::Solinas { plus, minus, .. } => {
+                    // Σ⁺ − Σ⁻ = 2^k − q > 0, so adding the positive terms first keeps x ≥ 0.
+                    let mut acc = low;
+                    for e in plus {
+                        acc = acc + (high.clone() << *e);
+                    }
+                    for e in minus {
+                        acc = acc - (high.clone() << *e);
+                    }
+                    acc
+                }
+                _ => unreachable!(),
+            };
+        }
+        while x >= self.modulus {
+            x = x - self.modulus.clone();
+        }
+        x
+    }
+}
+
+/// Signed binary (non-adjacent form) digits of a positive integer, as
+/// (exponent, ±1) pairs, or `None` if it has more than `SOLINAS_MAX_TERMS` of them.
+fn solinas_terms<T>(c: &T) -> Option<Vec<(u32, i8)>>
+where
+    T: Clone + Integer + FromPrimitive,
+{
+    let two = T::from_u8(2).unwrap();
+    let four = T::from_u8(4).unwrap();
+    let mut terms = Vec::new();
+    let mut n = c.clone();
+    let mut e = 0u32;
+    while n > T::zero() {
+        if n.is_odd() {
+            if n.mod_floor(&four) == T::one() {
+                terms.push((e, 1));
+                n = n - T::one();
+            } else {
+                terms.push((e, -1));
+                n = n + T::one();
+            }
+            if terms.len() > SOLINAS_MAX_TERMS {
+                return None;
+            }
+        }
+        n = n / two.clone();
+        e += 1;
+    }
+    Some(terms)
+}
+
+/// Folding only makes progress if the largest term is well below 2^k.
+fn solinas_applicable(k: u32, terms: &[(u32, i8)]) -> bool {
+    terms.iter().all(|(e, _)| *e + 16 <= k)
+}
+
+/// An element of a prime field F_q.
+///
+/// The value is always kept in the range [0, q), so the results of `+`, `-`,
+/// `*`, `/` and unary `-` never need an explicit reduction by the caller.
+/// Mixing elements of different fields is a logic error.
+#[derive(Clone)]
+pub struct Fp<T> {
+    value: T,
+    field: Arc<PrimeField<T>>,
+}
+
+impl<T> Fp<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    /// Returns the canonical integer representative in [0, q).
+    pub fn value(&self) -> T {
+        self.field.decode(&self.value)
+    }
+
+    /// Returns the field this element belongs to.
+    pub fn field(&self) -> &Arc<PrimeField<T>> {
+        &self.field
+    }
+
+    /// Returns true if this is the zero element.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns true if this is the one element.
+    pub fn is_one(&self) -> bool {
+        self.value == self.field.one
+    }
+
+    /// Returns 2·self.
+    pub fn double(&self) -> Self {
+        self + self
+    }
+
+    /// Returns self².
+    pub fn square(&self) -> Self {
+        self * self
+    }
+
+    /// Raises the element to a non-negative integer power using square-and-multiply.
+    pub fn pow(&self, exp: &T) -> Self {
+        let two = T::from_u8(2).unwrap();
+        let mut result = self.field.one();
+        let mut base = self.clone();
+        let mut e = exp.clone();
+        while e > T::zero() {
+            if e.is_odd() {
+                result = &result * &base;
+            }
+            e = e / two.clone();
+            base = base.square();
+        }
+        result
+    }
+
+    /// Computes the multiplicative inverse using the Extended Euclidean Algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NonInvertible` if the element is zero.
+    pub fn inverse(&self) -> Result<Self, EccError> {
+        if self.is_zero() {
+            return Err(EccError::NonInvertible);
+        }
+        let inv = Utils::mod_inv(self.value(), self.field.modulus.clone())?;
+        Ok(self.field.element(inv))
+    }
+
+    /// Legendre symbol (self / q): 0 for zero, 1 for a non-zero square and -1 otherwise.
+    pub fn legendre(&self) -> i8 {
+        if self.is_zero() {
+            return 0;
+        }
+        let q = self.field.modulus.clone();
+        let exp = (q - T::one()) / T::from_u8(2).unwrap();
+        if self.pow(&exp).is_one() { 1 } else { -1 }
+    }
+
+    /// Returns a square root of the element, if one exists.
+    ///
+    /// Uses the direct formulas for q ≡ 3 (mod 4) and q ≡ 5 (mod 8) and falls
+    /// back to Tonelli–Shanks otherwise. Which of the two roots is returned is
+    /// unspecified.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(self.clone());
+        }
+        if self.legendre() != 1 {
+            return None;
+        }
+        let one = T::one();
+        let two = T::from_u8(2).unwrap();
+        let q = self.field.modulus.clone();
+
+        let root = if q.mod_floor(&T::from_u8(4).unwrap()) == T::from_u8(3).unwrap() {
+            // x = a^((q+1)/4)
+            self.pow(&((q + one) / T::from_u8(4).unwrap()))
+        } else if q.mod_floor(&T::from_u8(8).unwrap()) == T::from_u8(5).unwrap() {
+            // x = a^((q+3)/8), multiplied by 2^((q-1)/4) = √-1 if needed.
+            let x = self.pow(&((q.clone() + T::from_u8(3).unwrap()) / T::from_u8(8).unwrap()));
+            if x.square() == *self {
+                x
+            } else {
+                let i = self.field.element(two).pow(&((q - one) / T::from_u8(4).unwrap()));
+                x * i
+            }
+        } else {
+            self.tonelli_shanks()
+        };
+
+        if root.square() == *self { Some(root) } else { None }
+    }
+
+    /// Tonelli–Shanks for a quadratic residue; see `Utils::tonelli_shanks`.
+    fn tonelli_shanks(&self) -> Self {
+        let one = T::one();
+        let two = T::from_u8(2).unwrap();
+        let q = self.field.modulus.clone();
+
+        // Write q - 1 as s_odd * 2^s with s_odd odd.
+        let mut s_odd = q - one.clone();
+        let mut s = 0u32;
+        while s_odd.is_even() {
+            s_odd = s_odd / two.clone();
+            s += 1;
+        }
+
+        // Find a quadratic non-residue z.
+        let mut z = self.field.element(two);
+        while z.legendre() != -1 {
+            z = z + self.field.one();
+        }
+
+        let mut c = z.pow(&s_odd);
+        let mut x = self.pow(&((s_odd.clone() + one) / T::from_u8(2).unwrap()));
+        let mut t = self.pow(&s_odd);
+        let mut m = s;
+        while !t.is_one() {
+            // Find the smallest i (0 < i < m) such that t^(2^i) = 1.
+            let mut i = 1;
+            let mut t2i = t.square();
+            while i < m && !t2i.is_one() {
+                t2i = t2i.square();
+                i += 1;
+            }
+            let mut b = c.clone();
+            for _ in 0..(m - i - 1) {
+                b = b.square();
+            }
+            x = x * &b;
+            c = b.square();
+            t = t * &c;
+            m = i;
+        }
+        x
+    }
+
+    fn check_field(&self, other: &Self) {
+        debug_assert!(
+            Arc::ptr_eq(&self.field, &other.field) || self.field == other.field,
+            "field elements belong to different fields"
+        );
+    }
+}
+
+impl<T> PartialEq for Fp<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+            && (Arc::ptr_eq(&self.field, &other.field) || *self.field == *other.field)
+    }
+}
+
+impl<T> Eq for Fp<T> where T: Eq {}
+
+impl<T> fmt::Debug for Fp<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} (mod {:?})", self.value(), self.field.modulus)
+    }
+}
+
+impl<T> fmt::Display for Fp<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug
+    + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+/// Implements a binary operator for every combination of owned and borrowed operands.
+macro_rules! impl_fp_binop {
+    ($trait:ident, $method:ident, $body:expr) => {
+        impl<T> $trait<&Fp<T>> for &Fp<T>
+        where
+            T: Clone
+            + Integer
+            + FromPrimitive
+            + Shl<u32, Output = T>
+            + Shr<u32, Output = T>
+            + BitAnd<Output = T>
+            + fmt::Debug,
+        {
+            type Output = Fp<T>;
+
+            fn $method(self, rhs: &Fp<T>) -> Fp<T> {
+                self.check_field(rhs);
+                #[allow(clippy::redundant_closure_call)]
+                $body(self, rhs)
+            }
+        }
+
+        impl<T> $trait<Fp<T>> for Fp<T>
+        where
+            T: Clone
+            + Integer
+            + FromPrimitive
+            + Shl<u32, Output = T>
+            + Shr<u32, Output = T>
+            + BitAnd<Output = T>
+            + fmt::Debug,
+        {
+            type Output = Fp<T>;
+
+            fn $method(self, rhs: Fp<T>) -> Fp<T> {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<T> $trait<&Fp<T>> for Fp<T>
+        where
+            T: Clone
+            + Integer
+            + FromPrimitive
+            + Shl<u32, Output = T>
+            + Shr<u32, Output = T>
+            + BitAnd<Output = T>
+            + fmt::Debug,
+        {
+            type Output = Fp<T>;
+
+            fn $method(self, rhs: &Fp<T>) -> Fp<T> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T> $trait<Fp<T>> for &Fp<T>
+        where
+            T: Clone
+            + Integer
+            + FromPrimitive
+            + Shl<u32, Output = T>
+            + Shr<u32, Output = T>
+            + BitAnd<Output = T>
+            + fmt::Debug,
+        {
+            type Output = Fp<T>;
+
+            fn $method(self, rhs: Fp<T>) -> Fp<T> {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_fp_binop!(Add, add, |a: &Fp<T>, b: &Fp<T>| Fp {
+    value: a.field.add(&a.value, &b.value),
+    field: Arc::clone(&a.field),
+});
+
+impl_fp_binop!(Sub, sub, |a: &Fp<T>, b: &Fp<T>| Fp {
+    value: a.field.sub(&a.value, &b.value),
+    field: Arc::clone(&a.field),
+});
+
+impl_fp_binop!(Mul, mul, |a: &Fp<T>, b: &Fp<T>| Fp {
+    value: a.field.mul(&a.value, &b.value),
+    field: Arc::clone(&a.field),
+});
+
+// Division panics on a zero divisor, like integer division does.
+impl_fp_binop!(Div, div, |a: &Fp<T>, b: &Fp<T>| a
+    * &b.inverse().expect("division by zero in prime field"));
+
+impl<T> Neg for &Fp<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    type Output = Fp<T>;
+
+    fn neg(self) -> Fp<T> {
+        Fp {
+            value: self.field.sub(&T::zero(), &self.value),
+            field: Arc::clone(&self.field),
+        }
+    }
+}
+
+impl<T> Neg for Fp<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    type Output = Fp<T>;
+
+    fn neg(self) -> Fp<T> {
+        -&self
+    }
+}
@@ -0,0 +1,130 @@
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::ecc::EllipticCurve;
+use crate::error::EccError;
+use crate::point::Point;
+
+/// The group of points E(F_q) ≅ Z/n1 × Z/n2, where n2 divides n1, together
+/// with generators of the two cyclic factors.
+#[derive(Debug, Clone)]
+pub struct GroupStructure<T> {
+    /// The exponent of the group, the largest order of a point.
+    pub n1: T,
+    /// The order of the second factor; 1 if the group is cyclic.
+    pub n2: T,
+    /// A point of order n1.
+    pub g1: Point<T>,
+    /// A point of order n2 with ⟨g1⟩ ∩ ⟨g2⟩ = {0}, so that every point is
+    /// uniquely i*g1 + j*g2 with 0 ≤ i < n1, 0 ≤ j < n2.
+    pub g2: Point<T>,
+}
+
+impl<T> GroupStructure<T>
+where
+    T: Clone
+    + Integer,
+{
+    /// The number of points n1*n2.
+    pub fn order(&self) -> T {
+        self.n1.clone() * self.n2.clone()
+    }
+
+    /// Returns true if the group is cyclic, generated by g1 alone.
+    pub fn is_cyclic(&self) -> bool {
+        self.n2.is_one()
+    }
+}
+
+/// Computes the structure of a group from the list of all its points, for
+/// example `curve.points().collect()`.
+///
+/// The point of largest order generates the first factor. The second is
+/// generated by any point of order n2 none of whose non-zero multiples lies in
+/// ⟨g1⟩; such a point exists because a cyclic subgroup of maximal order is a
+/// direct summand. Every point's order is computed and ⟨g1⟩ is listed, so this
+/// is only meant for small curves.
+///
+/// # Errors
+///
+/// Returns `EccError::NotOnCurve` if a point is not on the curve, and
+/// `EccError::InvalidParameters` if the points do not form a group of that
+/// shape, e.g. because some are missing.
+pub fn group_structure<T>(curve: &dyn EllipticCurve<T>, points: &[Point<T>]) -> Result<GroupStructure<T>, EccError>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let invalid = EccError::InvalidParameters("the points do not form a group");
+    let orders = points.iter().map(|p| curve.order(p)).collect::<Result<Vec<T>, EccError>>()?;
+    let (g1, n1) = points.iter().zip(&orders).max_by(|a, b| a.1.cmp(b.1)).ok_or(invalid)?;
+    let count = T::from_usize(points.len()).ok_or(invalid)?;
+    if !count.is_multiple_of(n1) {
+        return Err(invalid);
+    }
+    let n2 = count / n1.clone();
+    if !n1.is_multiple_of(&n2) {
+        return Err(invalid);
+    }
+    let g1 = reduce(curve, g1);
+    if n2.is_one() {
+        return Ok(GroupStructure { n1: n1.clone(), n2, g1, g2: curve.identity() });
+    }
+
+    // The non-zero multiples of g1, sorted by coordinates.
+    let mut cyclic = Vec::new();
+    let mut p = g1.clone();
+    while !curve.is_identity(&p) {
+        cyclic.push((p.x.clone(), p.y.clone()));
+        p = curve.add(&p, &g1);
+    }
+    cyclic.sort();
+    let in_cyclic = |p: &Point<T>| cyclic.binary_search(&(p.x.clone(), p.y.clone())).is_ok();
+
+    for (g2, _) in points.iter().zip(&orders).filter(|(_, n)| **n == n2) {
+        let g2 = reduce(curve, g2);
+        let mut p = g2.clone();
+        while !curve.is_identity(&p) && !in_cyclic(&p) {
+            p = curve.add(&p, &g2);
+        }
+        if curve.is_identity(&p) {
+            return Ok(GroupStructure { n1: n1.clone(), n2, g1, g2 });
+        }
+    }
+    Err(invalid)
+}
+
+/// The point with reduced coordinates, so that it compares equal to sums.
+fn reduce<T>(curve: &dyn EllipticCurve<T>, p: &Point<T>) -> Point<T>
+where
+    T: Zero
+    + One
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>,
+{
+    curve.neg(&curve.neg(p))
+}
@@ -1,9 +1,26 @@
+#![allow(non_snake_case)]
+
+pub mod error;
 pub mod ecc;
 pub mod weierstrass_ecc;
 pub mod utils;
+pub mod field;
+pub mod extended;
+pub(crate) mod edwards;
 pub mod edwards_curve;
 pub mod twisted_curve;
 pub mod montgomery_curve;
 pub mod point;
+pub mod scalar;
 
+pub mod rfc6979;
 pub mod dsa;
+pub mod eddsa;
+pub mod ecdh;
+pub mod schnorr;
+pub mod curves;
+pub mod spec;
+pub mod validate;
+pub mod order;
+pub mod schoof;
+pub mod group;
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use KissECC::ecc::{EllipticCurve};
 use KissECC::point::Point;
 use KissECC::twisted_curve::TwistedCurve;
@@ -8,7 +10,7 @@ fn main() {
 
         // For demonstration, using i32 as T (note: in cryptographic code, T will usually be a big integer type)
         let ecc = TwistedCurve::new( 2,  3,  17, 0 );
-        let p = Point { x: 1, y: 3 , z: 0};
+        let p = Point { x: 1, y: 3 , z: 1};
         println!("Is point valid? {}", ecc.is_valid(&p));
 
         println!("{}",ecc.display());
@@ -1,15 +1,20 @@
 use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
 use num_integer::Integer;
-use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr};
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
 use crate::ecc::{EllipticCurve};
+use crate::error::EccError;
+use crate::field::{Fp, PrimeField, Reduction};
+use crate::order::point_order;
 use crate::point::Point;
+use crate::scalar::Scalar;
 use crate::utils::Utils;
+use std::sync::Arc;
 
 /// Montgomery curve defined by:
 ///      B * y^2 = x^3 + A * x^2 + x   (mod q)
 ///
 /// We include the parameters A, B, the prime modulus q, and the group order.
-/// The identity (zero) point is represented as (0, 1, 0).
+/// The identity (zero) point is [`Point::identity`]; (0, 0) is the point of order two.
 ///
 #[allow(non_snake_case)]
 pub struct MontgomeryCurve<T> {
@@ -18,6 +23,7 @@ pub struct MontgomeryCurve<T> {
     pub q: T,
     pub order: T,
     pub zero: Point<T>,
+    field: Arc<PrimeField<T>>,
 }
 
 impl<T> MontgomeryCurve<T>
@@ -37,27 +43,206 @@ where
     + Mul<Output = T>
     + Rem<Output = T>
     + Div<Output = T>
-    + Copy,
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
 {
     /// Creates a new Montgomery curve with given parameters.
     /// Expects q to be a prime number > 2, and that the curve order is provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are rejected by [`MontgomeryCurve::try_new`].
     #[allow(non_snake_case)]
     pub fn new(A: T, B: T, q: T, order: T) -> Self {
-        assert!(q > T::from(2u8));
+        Self::try_new(A, B, q, order).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Creates a new Montgomery curve, or returns `EccError::InvalidParameters`
+    /// if q ≤ 2 or A or B is zero.
+    #[allow(non_snake_case)]
+    pub fn try_new(A: T, B: T, q: T, order: T) -> Result<Self, EccError> {
+        if q <= T::from(2u8) {
+            return Err(EccError::InvalidParameters("q must be greater than 2"));
+        }
         // We assume A and B are nonzero.
-        assert!(A != T::zero() && B != T::zero());
-        // Define the identity element.
-        let zero = Point {
-            x: T::zero(),
-            y: T::one(),
-            z: T::zero(),
-        };
-        MontgomeryCurve { A, B, q, order, zero }
+        if A.is_zero() || B.is_zero() {
+            return Err(EccError::InvalidParameters("A and B must be nonzero"));
+        }
+        let zero = Point::identity();
+        // Generic reduction is the fastest with BigInt; see `with_reduction` for the others.
+        let field = PrimeField::new(q.clone());
+        Ok(MontgomeryCurve { A, B, q, order, zero, field })
+    }
+
+    /// Returns the base field F_q of the curve.
+    pub fn field(&self) -> &Arc<PrimeField<T>> {
+        &self.field
+    }
+
+    /// Selects how products in the base field are reduced. The curve starts with
+    /// [`Reduction::Generic`]; the other strategies, including the special forms
+    /// found by [`Reduction::detect`], are opt-in.
+    pub fn with_reduction(mut self, reduction: Reduction) -> Self {
+        self.field = PrimeField::with_reduction(self.q.clone(), reduction);
+        self
+    }
+
+    /// Lifts an integer into the base field.
+    fn fp(&self, v: &T) -> Fp<T> {
+        self.field.element(v.clone())
+    }
+
+    /// Iterates over every point of the curve, the identity first, by solving
+    /// B*y² = x³ + A*x² + x for each x. This takes q square roots, so it is
+    /// only meant for small curves.
+    pub fn points(&self) -> impl Iterator<Item = Point<T>> + '_ {
+        let finite = Utils::residues(&self.q).flat_map(move |x| {
+            let x = self.fp(&x);
+            let y2 = (x.square() * &x + self.fp(&self.A) * x.square() + &x) / self.fp(&self.B);
+            match y2.sqrt() {
+                Some(y) if y.is_zero() => vec![Point::new(x.value(), y.value())],
+                Some(y) => vec![Point::new(x.value(), y.value()), Point::new(x.value(), (-y).value())],
+                None => vec![],
+            }
+        });
+        std::iter::once(Point::identity()).chain(finite)
+    }
+
+    /// One combined x-only doubling and differential addition step (xDBLADD).
+    ///
+    /// Given the affine x-coordinate `x1` of P − Q, and projective (X : Z)
+    /// coordinates of P and Q, returns (2P, P + Q), following RFC 7748 §5:
+    ///
+    ///   A = X₂+Z₂, B = X₂−Z₂, E = A² − B², C = X₃+Z₃, D = X₃−Z₃,
+    ///   X₅ = (DA + CB)², Z₅ = x₁*(DA − CB)²,
+    ///   X₄ = A²*B²,      Z₄ = E*(A² + a24*E),   with a24 = (A − 2)/4.
+    #[allow(clippy::type_complexity)]
+    pub fn x_dbl_add(
+        &self,
+        x1: &Fp<T>,
+        p: (&Fp<T>, &Fp<T>),
+        q: (&Fp<T>, &Fp<T>),
+    ) -> ((Fp<T>, Fp<T>), (Fp<T>, Fp<T>)) {
+        Self::x_dbl_add_with(&self.a24(), x1, p, q)
+    }
+
+    /// The ladder constant a24 = (A − 2)/4.
+    fn a24(&self) -> Fp<T> {
+        (self.fp(&self.A) - self.fp(&T::from(2u8))) / self.fp(&T::from(4u8))
+    }
+
+    /// [`MontgomeryCurve::x_dbl_add`] with a24 computed once by the caller.
+    #[allow(clippy::type_complexity)]
+    fn x_dbl_add_with(
+        a24: &Fp<T>,
+        x1: &Fp<T>,
+        p: (&Fp<T>, &Fp<T>),
+        q: (&Fp<T>, &Fp<T>),
+    ) -> ((Fp<T>, Fp<T>), (Fp<T>, Fp<T>)) {
+        let (x2, z2) = p;
+        let (x3, z3) = q;
+        let a = x2 + z2;
+        let aa = a.square();
+        let b = x2 - z2;
+        let bb = b.square();
+        let e = &aa - &bb;
+        let c = x3 + z3;
+        let d = x3 - z3;
+        let da = d * a;
+        let cb = c * b;
+        let x5 = (&da + &cb).square();
+        let z5 = x1 * (da - cb).square();
+        let x4 = &aa * bb;
+        let z4 = &e * (aa + a24 * &e);
+        ((x4, z4), (x5, z5))
+    }
+
+    /// Montgomery ladder on projective x-coordinates.
+    ///
+    /// Returns ((X : Z) of n·P, (X : Z) of (n+1)·P) for the point P with
+    /// u-coordinate `u`. The point at infinity has Z = 0.
+    #[allow(clippy::type_complexity)]
+    fn ladder_projective(&self, n: &T, u: &Fp<T>) -> ((Fp<T>, Fp<T>), (Fp<T>, Fp<T>)) {
+        let a24 = self.a24();
+        let mut r0 = (self.field.one(), self.field.zero());
+        let mut r1 = (u.clone(), self.field.one());
+        for i in (0..Utils::bit_length(n)).rev() {
+            let bit = (n.clone() >> i) & T::one();
+            if bit.is_zero() {
+                // (R0, R1) ← (2R0, R0 + R1)
+                let (d, a) = Self::x_dbl_add_with(&a24, u, (&r0.0, &r0.1), (&r1.0, &r1.1));
+                r0 = d;
+                r1 = a;
+            } else {
+                // (R0, R1) ← (R0 + R1, 2R1)
+                let (d, a) = Self::x_dbl_add_with(&a24, u, (&r1.0, &r1.1), (&r0.0, &r0.1));
+                r0 = a;
+                r1 = d;
+            }
+        }
+        (r0, r1)
+    }
+
+    /// x-only scalar multiplication with the Montgomery ladder.
+    ///
+    /// Takes the u-coordinate of a point P and returns the u-coordinate of n·P,
+    /// without ever computing y. As in RFC 7748, the point at infinity maps to 0.
+    pub fn ladder(&self, n: &T, u: &T) -> T {
+        let ((x, z), _) = self.ladder_projective(n, &self.fp(u));
+        match z.inverse() {
+            Ok(z_inv) => (x * z_inv).value(),
+            Err(_) => T::zero(),
+        }
     }
 
-    /// Helper: checks whether a given point is the identity.
-    fn is_zero(&self, p: &Point<T>) -> bool {
-        *p == self.zero
+    /// Okeya–Sakurai y-coordinate recovery.
+    ///
+    /// Given a finite point P = (x, y) with y ≠ 0, and the x-coordinates of
+    /// Q and Q + P (both finite), returns Q with its y-coordinate:
+    ///
+    ///   y_Q = ((x_Q*x + 1)(x_Q + x + 2A) − 2A − (x_Q − x)²*x_{Q+P}) / (2*B*y)
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NonInvertible` if y = 0.
+    pub fn recover_y(&self, p: &Point<T>, x_q: &T, x_q_plus_p: &T) -> Result<Point<T>, EccError> {
+        let (x, y) = (self.fp(&p.x), self.fp(&p.y));
+        let (xq, xqp) = (self.fp(x_q), self.fp(x_q_plus_p));
+        let two_a = self.fp(&self.A).double();
+        let numerator = (&xq * &x + self.field.one()) * (&xq + &x + &two_a)
+            - two_a
+            - (&xq - &x).square() * xqp;
+        let denominator = (self.fp(&self.B) * y).double();
+        let yq = numerator * denominator.inverse()?;
+        Ok(Point { x: xq.value(), y: yq.value(), z: T::one() })
+    }
+
+    /// Full scalar multiplication n·P through the x-only ladder followed by
+    /// Okeya–Sakurai y recovery (a single inversion for each of x and y).
+    pub fn ladder_mul(&self, n: &T, p: &Point<T>) -> Point<T> {
+        if p.is_identity() || n.is_zero() {
+            return self.zero.clone();
+        }
+        let x = self.fp(&p.x);
+        let y = self.fp(&p.y);
+        if y.is_zero() {
+            // P has order two.
+            return if n.is_odd() { p.clone() } else { self.zero.clone() };
+        }
+        let ((xq, zq), (xqp, zqp)) = self.ladder_projective(n, &x);
+        let Ok(zq_inv) = zq.inverse() else {
+            return self.zero.clone();
+        };
+        let xq = xq * zq_inv;
+        let Ok(zqp_inv) = zqp.inverse() else {
+            // Q + P = O, so Q = −P.
+            return Point { x: x.value(), y: (-y).value(), z: T::one() };
+        };
+        let xqp = xqp * zqp_inv;
+        self.recover_y(p, &xq.value(), &xqp.value())
+            .expect("y is non-zero")
     }
 }
 
@@ -78,29 +263,28 @@ where
     + Mul<Output = T>
     + Rem<Output = T>
     + Div<Output = T>
-    + Copy
     + BitAnd<Output = T>
-    + Shr<u32, Output = T>,
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
 {
     /// A point is valid if it is the identity, or if it satisfies
     ///      B*y^2 = x^3 + A*x^2 + x  (mod q).
     fn is_valid(&self, p: &Point<T>) -> bool {
-        if self.is_zero(p) {
+        if p.is_identity() {
             return true;
         }
-        let x = p.x.clone();
-        let y = p.y.clone();
-        let left = (self.B * y.clone() * y.clone()) % self.q.clone();
-        let right = (x.clone() * x.clone() * x.clone()
-            + self.A * x.clone() * x.clone()
-            + x) % self.q.clone();
+        let x = self.fp(&p.x);
+        let y = self.fp(&p.y);
+        let left = self.fp(&self.B) * y.square();
+        let right = (x.square() * &x) + (self.fp(&self.A) * x.square()) + x;
         left == right
     }
 
     /// Given an x-coordinate, we cannot in general recover y uniquely on a Montgomery curve.
     /// Here we return an error.
-    fn at(&self, _x: T) -> Result<(Point<T>, Point<T>), &'static str> {
-        Err("Method 'at' is not implemented for Montgomery curves")
+    fn at(&self, _x: T) -> Result<(Point<T>, Point<T>), EccError> {
+        Err(EccError::Unsupported("at is not implemented for Montgomery curves"))
     }
 
     /// Adds two points p and Q.
@@ -120,52 +304,38 @@ where
     ///   y₃ = λ*(x₁ − x₃) − y₁
     fn add(&self, p: &Point<T>, _q: &Point<T>) -> Point<T> {
         // Handle identity cases.
-        if self.is_zero(p) {
+        if p.is_identity() {
             return _q.clone();
         }
-        if self.is_zero(_q) {
+        if _q.is_identity() {
             return p.clone();
         }
-        // If x1 == x2 and y1 != y2, then p + _q = 0.
-        if p.x == _q.x && p.y != _q.y {
+        let (x1, y1) = (self.fp(&p.x), self.fp(&p.y));
+        let (x2, y2) = (self.fp(&_q.x), self.fp(&_q.y));
+        let (a, b) = (self.fp(&self.A), self.fp(&self.B));
+        // If x1 == x2 and y1 != y2 (or y1 = 0 when doubling), then p + _q = 0.
+        if x1 == x2 && (y1 != y2 || y1.is_zero()) {
             return self.zero.clone();
         }
         // Determine whether we are doubling or adding distinct points.
-        let (_lambda, x3, y3) = if p == _q {
+        let lambda = if x1 == x2 {
             // Doubling: p = _q.
             // λ = (3*x₁² + 2*A*x₁ + 1)/(2*B*y₁)
-            let numerator = (T::from(3u8) * p.x.clone() * p.x.clone()
-                + (T::from(2u8) * self.A * p.x.clone())
-                + T::one()) % self.q.clone();
-            let denominator = (T::from(2u8) * self.B * p.y.clone()) % self.q.clone();
-            let inv_den = Utils::mod_inv(denominator, self.q.clone())
-                .expect("Denom invertible in doubling");
-            let lambda = (numerator * inv_den) % self.q.clone();
-            // x₃ = B*λ² − A − 2*x₁
-            let x3 = (self.B * lambda.clone() * lambda.clone()
-                - self.A - (T::from(2u8) * p.x.clone())) % self.q.clone();
-            // y₃ = λ*(x₁ − x₃) − y₁
-            let y3 = (lambda * (p.x.clone() - x3.clone()) - p.y.clone()) % self.q.clone();
-            (lambda, x3, y3)
+            let three = self.fp(&T::from(3u8));
+            let numerator = three * x1.square() + (a.double() * &x1) + self.field.one();
+            numerator / (b.double() * &y1)
         } else {
             // Addition: p != _q.
             // λ = (y₂ − y₁)/(x₂ − x₁)
-            let numerator = (_q.y.clone() - p.y.clone()) % self.q.clone();
-            let denominator = (_q.x.clone() - p.x.clone()) % self.q.clone();
-            let inv_den = Utils::mod_inv(denominator, self.q.clone())
-                .expect("Denom invertible in addition");
-            let lambda = (numerator * inv_den) % self.q.clone();
-            // x₃ = B*λ² − A − x₁ − x₂
-            let x3 = (self.B * lambda.clone() * lambda.clone()
-                - self.A - p.x.clone() - _q.x.clone()) % self.q.clone();
-            // y₃ = λ*(x₁ − x₃) − y₁
-            let y3 = (lambda * (p.x.clone() - x3.clone()) - p.y.clone()) % self.q.clone();
-            (lambda, x3, y3)
+            (y2 - &y1) / (&x2 - &x1)
         };
-        // Normalize (make sure the result is positive modulo q).
+        // x₃ = B*λ² − A − x₁ − x₂
+        let x3 = b * lambda.square() - a - &x1 - x2;
+        // y₃ = λ*(x₁ − x₃) − y₁
+        let y3 = lambda * (x1 - &x3) - y1;
         Point {
-            x: (x3 + self.q.clone()) % self.q.clone(),
-            y: (y3 + self.q.clone()) % self.q.clone(),
+            x: x3.value(),
+            y: y3.value(),
             z: T::one(), // For finite points we set z = 1.
         }
     }
@@ -175,32 +345,23 @@ where
         self.add(p, p)
     }
 
-    /// Scalar multiplication via the double-and-add algorithm.
-    fn mul(&self, mut n: i32, p: &Point<T>) -> Point<T> {
-        let mut r = self.zero.clone();
-        let mut m2 = p.clone();
-        while n > 0 {
-            if (n.clone() & 1) == 1 {
-                r = self.add(&r, &m2);
-            }
-            n = n >> 1;
-            m2 = self.add(&m2, &m2);
+    /// Negation: −(x, y) = (x, −y).
+    fn neg(&self, p: &Point<T>) -> Point<T> {
+        if p.is_identity() {
+            return Point::identity();
         }
-        r
-    }
-
-    /// Computes the order of a point by repeatedly adding it until the identity is reached.
-    fn order(&self, g: &Point<T>) -> Result<T, &'static str> {
-        let mut order = T::one();
-        let mut current = g.clone();
-        while current != self.zero {
-            order = order + T::one();
-            current = self.add(&current, g);
-            if order > self.q {
-                return Err("Order not found within group bounds");
-            }
-        }
-        Ok(order)
+        Point::new(self.fp(&p.x).value(), (-self.fp(&p.y)).value())
+    }
+
+    /// Scalar multiplication via the Montgomery ladder with y recovery.
+    fn mul(&self, n: &Scalar<T>, p: &Point<T>) -> Point<T> {
+        let r = self.ladder_mul(&n.magnitude(), p);
+        if n.is_negative() { self.neg(&r) } else { r }
+    }
+
+    /// Computes the order of a point by baby-step giant-step, see [`point_order`].
+    fn order(&self, g: &Point<T>) -> Result<T, EccError> {
+        point_order(self, g, &self.q)
     }
 
     /// Returns a string representation of the Montgomery curve.
@@ -0,0 +1,110 @@
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::ecc::EllipticCurve;
+use crate::error::EccError;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use crate::utils::Utils;
+
+/// Largest number of baby steps kept in memory, enough for fields of about
+/// 76 bits.
+pub const MAX_BABY_STEPS: usize = 1 << 20;
+
+/// Computes the order of `g` on a curve over F_q by baby-step giant-step.
+///
+/// The group has N points with q + 1 − 2√q ≤ N ≤ q + 1 + 2√q (Hasse), and
+/// the order of g divides N. With m > √(4√q + 1), the baby steps j*g for
+/// 0 < j < m and the giant steps (lo + i*m)*g, where lo is the bottom of the
+/// interval, find some M in the interval with M*g = 0 after about 4q^¼
+/// additions. M is then factored and each prime is stripped off while
+/// (M/p)*g stays the identity, which leaves the exact order. Orders below m
+/// are found directly by the baby steps.
+///
+/// # Errors
+///
+/// Returns `EccError::NotOnCurve` if g is not on the curve, and
+/// `EccError::OrderNotFound` if q is too large for [`MAX_BABY_STEPS`] or no
+/// multiple lies in the Hasse interval (q not prime, or a singular curve).
+pub fn point_order<T>(curve: &dyn EllipticCurve<T>, g: &Point<T>, q: &T) -> Result<T, EccError>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    if !curve.is_valid(g) {
+        return Err(EccError::NotOnCurve);
+    }
+    if curve.is_identity(g) {
+        return Ok(T::one());
+    }
+    // Reduced coordinates, so that g compares equal to the other baby steps.
+    let g = &curve.neg(&curve.neg(g));
+    // The interval [lo, lo + width] with width = 2⌊2√q⌋.
+    let w = Utils::isqrt(&(T::from(4u8) * q.clone()));
+    let lo = q.clone() + T::one() - w.clone();
+    let width = w.clone() + w;
+    let m = Utils::isqrt(&width) + T::one();
+    let steps = m.to_usize().filter(|&m| m <= MAX_BABY_STEPS).ok_or(EccError::OrderNotFound)?;
+
+    // Baby steps j*g for 0 < j < m, sorted by coordinates.
+    let mut baby: Vec<(T, T, usize)> = Vec::with_capacity(steps);
+    let mut p = g.clone();
+    for j in 1..steps {
+        if curve.is_identity(&p) {
+            return T::from_usize(j).ok_or(EccError::OrderNotFound);
+        }
+        baby.push((p.x.clone(), p.y.clone(), j));
+        p = curve.add(&p, g);
+    }
+    baby.sort();
+
+    // Giant steps: find (lo + i*m)*g = −j*g, so that (lo + i*m + j)*g = 0.
+    let stride = curve.mul(&Scalar::new(m.clone()), g);
+    let mut giant = curve.mul(&Scalar::new(lo.clone()), g);
+    let hi = lo.clone() + width;
+    let mut offset = lo;
+    let mut multiple = None;
+    while offset <= hi {
+        if curve.is_identity(&giant) {
+            multiple = Some(offset);
+            break;
+        }
+        let target = curve.neg(&giant);
+        let found = baby.binary_search_by(|(x, y, _)| (x, y).cmp(&(&target.x, &target.y)));
+        if let Ok(i) = found {
+            multiple = T::from_usize(baby[i].2).map(|j| offset.clone() + j);
+            break;
+        }
+        giant = curve.add(&giant, &stride);
+        offset = offset + m.clone();
+    }
+    let mut order = multiple.ok_or(EccError::OrderNotFound)?;
+
+    // Strip prime factors that are not needed.
+    for (prime, exponent) in Utils::factor(&order) {
+        for _ in 0..exponent {
+            let smaller = order.clone() / prime.clone();
+            if !curve.is_identity(&curve.mul(&Scalar::new(smaller.clone()), g)) {
+                break;
+            }
+            order = smaller;
+        }
+    }
+    Ok(order)
+}
@@ -1,22 +1,66 @@
 use num_traits::{Zero, One};
+use num_integer::Integer;
 use std::ops::{Rem};
 use crate::utils::Utils;
 
 /// A simple point structure on a curve.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// `z` flags the point at infinity explicitly: an affine point (x, y) has
+/// z = 1, and any point with z = 0 is the identity, whatever its x and y. The
+/// canonical identity is (0, 1, 0), see [`Point::identity`]. Every curve model
+/// returns finite points with z = 1 and the identity in canonical form, so a
+/// real point such as (0, 0) on y² = x³ + ax is never mistaken for infinity.
+#[derive(Debug, Clone)]
 pub struct Point<T> {
     pub x: T,
     pub y: T,
     pub z: T,
 }
 
+impl<T> Point<T>
+where
+    T: Zero + One,
+{
+    /// Creates the affine point (x, y).
+    pub fn new(x: T, y: T) -> Self {
+        Point { x, y, z: T::one() }
+    }
+
+    /// The point at infinity, (0, 1, 0).
+    pub fn identity() -> Self {
+        Point { x: T::zero(), y: T::one(), z: T::zero() }
+    }
+
+    /// Returns true for the point at infinity (z = 0).
+    pub fn is_identity(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+/// Two identities are equal whatever their x and y; finite points compare
+/// coordinate-wise.
+impl<T> PartialEq for Point<T>
+where
+    T: Zero + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self.z.is_zero(), other.z.is_zero()) {
+            (true, true) => true,
+            (false, false) => self.x == other.x && self.y == other.y && self.z == other.z,
+            _ => false,
+        }
+    }
+}
+
+impl<T> Eq for Point<T> where T: Zero + Eq {}
+
 impl<T> Point<T>
 where
     T: Clone
-    + Copy
     + One
     + Zero
     + PartialEq
+    + Integer
     + Rem<Output = T>
     + From<u8>
     + std::cmp::PartialOrd
@@ -26,20 +70,18 @@ where
     + std::fmt::Debug,
 
 {
+    /// Compares two points given in projective form (x/z, y/z) modulo q.
     pub fn eq_affine(&self, other: &Self, q: T) -> bool {
-        // If one point is the identity (conventionally z=0), check using the chosen identity representation.
-        if self.z == T::zero() || other.z == T::zero() {
-            // Compare based on your identity representation.
-            return self == other;
+        if self.is_identity() || other.is_identity() {
+            return self.is_identity() && other.is_identity();
         }
         // Otherwise, normalize (i.e., compare x/z and y/z).
-        let inv_self_z = Utils::mod_inv(self.z, q.clone()).unwrap();
-        let inv_other_z = Utils::mod_inv(other.z, q.clone()).unwrap();
-        let x1 = (self.x.clone() * inv_self_z) % q.clone();
-        let y1 = (self.y.clone() * inv_self_z) % q.clone();
-        let x2 = (other.x.clone() * inv_other_z) % q.clone();
-        let y2 = (other.y.clone() * inv_other_z) % q;
+        let inv_self_z = Utils::mod_inv(self.z.clone(), q.clone()).unwrap();
+        let inv_other_z = Utils::mod_inv(other.z.clone(), q.clone()).unwrap();
+        let x1 = (self.x.clone() * inv_self_z.clone()).mod_floor(&q);
+        let y1 = (self.y.clone() * inv_self_z).mod_floor(&q);
+        let x2 = (other.x.clone() * inv_other_z.clone()).mod_floor(&q);
+        let y2 = (other.y.clone() * inv_other_z).mod_floor(&q);
         x1 == x2 && y1 == y2
     }
 }
-
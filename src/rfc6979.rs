@@ -0,0 +1,140 @@
+use hmac::{Mac, SimpleHmac};
+use num_traits::{FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use sha2::Digest;
+use sha2::digest::core_api::BlockSizeUser;
+use std::marker::PhantomData;
+use std::ops::{BitAnd, Shl, Shr};
+use crate::scalar::Scalar;
+use crate::utils::Utils;
+
+/// HMAC_DRBG (NIST SP 800-90A §10.1.2) without reseeding, over the hash `D`.
+///
+/// This is the generator RFC 6979 §3.2 runs to derive signature nonces.
+pub struct HmacDrbg<D> {
+    k: Vec<u8>,
+    v: Vec<u8>,
+    digest: PhantomData<D>,
+}
+
+impl<D> HmacDrbg<D>
+where
+    D: Digest + BlockSizeUser + Clone,
+{
+    /// Instantiates the generator: K = 0x00…00, V = 0x01…01, then mixes in `seed`.
+    pub fn new(seed: &[u8]) -> Self {
+        let len = <D as Digest>::output_size();
+        let mut drbg = HmacDrbg {
+            k: vec![0x00; len],
+            v: vec![0x01; len],
+            digest: PhantomData,
+        };
+        drbg.update(seed);
+        drbg
+    }
+
+    /// HMAC_K(V || byte || data).
+    fn hmac(&self, byte: Option<u8>, data: &[u8]) -> Vec<u8> {
+        let mut mac = <SimpleHmac<D> as Mac>::new_from_slice(&self.k).expect("HMAC accepts keys of any length");
+        mac.update(&self.v);
+        if let Some(byte) = byte {
+            mac.update(&[byte]);
+        }
+        mac.update(data);
+        mac.finalize().into_bytes().to_vec()
+    }
+
+    /// The HMAC_DRBG update function:
+    ///
+    ///   K = HMAC_K(V || 0x00 || data), V = HMAC_K(V),
+    ///   and, if data is not empty, K = HMAC_K(V || 0x01 || data), V = HMAC_K(V).
+    pub fn update(&mut self, data: &[u8]) {
+        self.k = self.hmac(Some(0x00), data);
+        self.v = self.hmac(None, &[]);
+        if !data.is_empty() {
+            self.k = self.hmac(Some(0x01), data);
+            self.v = self.hmac(None, &[]);
+        }
+    }
+
+    /// Fills `out` with the blocks V = HMAC_K(V), then updates the state so
+    /// that the next call returns fresh output.
+    pub fn fill_bytes(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(self.v.len()) {
+            self.v = self.hmac(None, &[]);
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[]);
+    }
+}
+
+/// Deterministic nonces for (EC)DSA, as specified by RFC 6979 §3.2.
+///
+/// The nonce stream depends only on the private key x, the message digest h1
+/// and the group order q, so signing the same message twice gives the same
+/// signature and no random number generator is needed. Successive calls to
+/// [`NonceGenerator::next_k`] return the candidates of step h, which are only
+/// needed again when a nonce yields r = 0 or s = 0.
+pub struct NonceGenerator<T, D> {
+    q: T,
+    qlen: u32,
+    drbg: HmacDrbg<D>,
+}
+
+impl<T, D> NonceGenerator<T, D>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + ToPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+    D: Digest + BlockSizeUser + Clone,
+{
+    /// Seeds the generator with int2octets(x) || bits2octets(h1).
+    pub fn new(q: &T, x: &T, h1: &[u8]) -> Self {
+        let qlen = Scalar::new(q.clone()).bits();
+        let rlen = qlen.div_ceil(8) as usize;
+        let mut seed = Utils::to_bytes_be(x, rlen);
+        // bits2octets(h1) = int2octets(bits2int(h1) mod q).
+        let z = bits2int::<T>(h1, qlen).mod_floor(q);
+        seed.extend(Utils::to_bytes_be(&z, rlen));
+        NonceGenerator {
+            q: q.clone(),
+            qlen,
+            drbg: HmacDrbg::new(&seed),
+        }
+    }
+
+    /// Returns the next nonce k in [1, q − 1].
+    pub fn next_k(&mut self) -> T {
+        let mut t = vec![0u8; self.qlen.div_ceil(8) as usize];
+        loop {
+            self.drbg.fill_bytes(&mut t);
+            let k = bits2int::<T>(&t, self.qlen);
+            if k > T::zero() && k < self.q {
+                return k;
+            }
+        }
+    }
+}
+
+/// Converts a bit string to an integer, keeping only its leftmost `qlen` bits
+/// (bits2int of RFC 6979 §2.3.2).
+pub fn bits2int<T>(data: &[u8], qlen: u32) -> T
+where
+    T: Clone + Integer + FromPrimitive + Shl<u32, Output = T> + Shr<u32, Output = T>,
+{
+    // Only the bytes holding the leftmost bits are read, so that the
+    // intermediate value fits in T even for small machine integers.
+    let take = data.len().min(qlen.div_ceil(8) as usize);
+    let v: T = Utils::from_bytes_be(&data[..take]);
+    let read_bits = 8 * take as u32;
+    if read_bits > qlen {
+        v >> (read_bits - qlen)
+    } else {
+        v
+    }
+}
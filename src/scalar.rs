@@ -0,0 +1,226 @@
+use num_traits::FromPrimitive;
+use num_integer::Integer;
+use rand::Rng;
+use std::fmt;
+use crate::error::EccError;
+use crate::utils::Utils;
+use std::ops::{Add, Sub, Mul, Neg, Shl, Shr, BitAnd};
+
+/// A scalar for point multiplication.
+///
+/// A scalar is either a plain (possibly negative) integer of arbitrary width, or
+/// an integer modulo a group order n, in which case it is kept in [0, n) and
+/// `+`, `-`, `*` and unary `-` are performed modulo n. Multiplying a point by a
+/// negative scalar −k yields −(k·P).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scalar<T> {
+    value: T,
+    order: Option<T>,
+}
+
+impl<T> Scalar<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    /// Creates a plain integer scalar, without any reduction.
+    pub fn new(value: T) -> Self {
+        Scalar { value, order: None }
+    }
+
+    /// Creates a scalar modulo the group order `n`, reducing `value` into [0, n).
+    pub fn modulo(value: T, n: &T) -> Self {
+        assert!(*n > T::zero(), "the group order must be positive");
+        Scalar {
+            value: value.mod_floor(n),
+            order: Some(n.clone()),
+        }
+    }
+
+    /// Draws a uniformly random scalar in [1, n) using the thread-local RNG.
+    pub fn random(n: &T) -> Self {
+        assert!(*n > T::one(), "the group order must be greater than 1");
+        let bits = Utils::bit_length(n);
+        let mut rng = rand::rng();
+        loop {
+            // Rejection sampling over bit strings of the same length as n.
+            let mut candidate = T::zero();
+            let mut filled = 0;
+            while filled < bits {
+                let take = (bits - filled).min(32);
+                let word: u32 = rng.random::<u32>() >> (32 - take);
+                candidate = (candidate << take) + T::from_u32(word).unwrap();
+                filled += take;
+            }
+            if candidate > T::zero() && candidate < *n {
+                return Scalar::modulo(candidate, n);
+            }
+        }
+    }
+
+    /// Returns the integer value of the scalar.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the group order the scalar is reduced by, if any.
+    pub fn order(&self) -> Option<&T> {
+        self.order.as_ref()
+    }
+
+    /// Returns true if the scalar is zero.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns true if the scalar is a negative plain integer.
+    pub fn is_negative(&self) -> bool {
+        self.value < T::zero()
+    }
+
+    /// Returns |value|, the number of times a point is added to itself.
+    pub fn magnitude(&self) -> T {
+        if self.is_negative() {
+            T::zero() - self.value.clone()
+        } else {
+            self.value.clone()
+        }
+    }
+
+    /// Number of bits of |value|.
+    pub fn bits(&self) -> u32 {
+        Utils::bit_length(&self.magnitude())
+    }
+
+    /// Returns bit `i` of |value|.
+    pub fn bit(&self, i: u32) -> bool {
+        !((self.magnitude() >> i) & T::one()).is_zero()
+    }
+
+    /// Computes the multiplicative inverse modulo the group order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NonInvertible` if the scalar has no order or is not
+    /// coprime to it.
+    pub fn inverse(&self) -> Result<Self, EccError> {
+        let n = self.order.as_ref().ok_or(EccError::NonInvertible)?;
+        let t = Utils::mod_inv(self.value.clone(), n.clone())?;
+        Ok(Scalar::modulo(t, n))
+    }
+
+    /// Combines two scalars, reducing by whichever order is set.
+    fn combine(&self, other: &Self, value: T) -> Self {
+        match self.order.as_ref().or(other.order.as_ref()) {
+            Some(n) => Scalar::modulo(value, n),
+            None => Scalar::new(value),
+        }
+    }
+}
+
+impl<T> From<i32> for Scalar<T>
+where
+    T: FromPrimitive,
+{
+    fn from(n: i32) -> Self {
+        Scalar {
+            value: T::from_i32(n).expect("i32 fits in the scalar type"),
+            order: None,
+        }
+    }
+}
+
+impl<T> fmt::Display for Scalar<T>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Implements a binary operator on references and owned values.
+macro_rules! impl_scalar_binop {
+    ($trait:ident, $method:ident) => {
+        impl<T> $trait<&Scalar<T>> for &Scalar<T>
+        where
+            T: Clone
+            + Integer
+            + FromPrimitive
+            + Shl<u32, Output = T>
+            + Shr<u32, Output = T>
+            + BitAnd<Output = T>
+            + fmt::Debug,
+        {
+            type Output = Scalar<T>;
+
+            fn $method(self, rhs: &Scalar<T>) -> Scalar<T> {
+                debug_assert!(
+                    self.order.is_none() || rhs.order.is_none() || self.order == rhs.order,
+                    "scalars reduced by different orders"
+                );
+                self.combine(rhs, self.value.clone().$method(rhs.value.clone()))
+            }
+        }
+
+        impl<T> $trait<Scalar<T>> for Scalar<T>
+        where
+            T: Clone
+            + Integer
+            + FromPrimitive
+            + Shl<u32, Output = T>
+            + Shr<u32, Output = T>
+            + BitAnd<Output = T>
+            + fmt::Debug,
+        {
+            type Output = Scalar<T>;
+
+            fn $method(self, rhs: Scalar<T>) -> Scalar<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_binop!(Add, add);
+impl_scalar_binop!(Sub, sub);
+impl_scalar_binop!(Mul, mul);
+
+impl<T> Neg for &Scalar<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    type Output = Scalar<T>;
+
+    fn neg(self) -> Scalar<T> {
+        self.combine(self, T::zero() - self.value.clone())
+    }
+}
+
+impl<T> Neg for Scalar<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + fmt::Debug,
+{
+    type Output = Scalar<T>;
+
+    fn neg(self) -> Scalar<T> {
+        -&self
+    }
+}
@@ -0,0 +1,243 @@
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::curves;
+use crate::ecc::EllipticCurve;
+use crate::error::EccError;
+use crate::point::Point;
+use crate::scalar::Scalar;
+use crate::utils::Utils;
+use crate::weierstrass_ecc::WeierstrassECC;
+
+/// tagged_hash(tag, x) = SHA-256(SHA-256(tag) || SHA-256(tag) || x), with x
+/// the concatenated parts (BIP-340).
+pub fn tagged_hash(tag: &str, parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// BIP-340 Schnorr signatures on secp256k1: y² = x³ + 7 over 2^256 − 2^32 − 977.
+///
+/// Public keys are the 32-byte x-coordinate of a point with even y, and
+/// signatures are 64 bytes R.x || s where R also has even y. Secret keys are
+/// negated as needed so that the public point has even y, and nonces are
+/// derived from the secret key, the message and 32 bytes of auxiliary
+/// randomness. `T` must hold integers of at least 512 bits, e.g. `BigInt`.
+pub struct Bip340<T> {
+    curve: WeierstrassECC<T>,
+    g: Point<T>,
+    n: T,
+}
+
+impl<T> Bip340<T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    /// Sets up secp256k1 ([`curves::secp256k1`]) with its standard generator G
+    /// of prime order n.
+    pub fn new() -> Self {
+        let secp256k1 = curves::secp256k1();
+        Bip340 { curve: secp256k1.curve, g: secp256k1.generator, n: secp256k1.order }
+    }
+
+    /// Returns the underlying Weierstrass curve.
+    pub fn curve(&self) -> &WeierstrassECC<T> {
+        &self.curve
+    }
+
+    /// Returns the generator G.
+    pub fn generator(&self) -> &Point<T> {
+        &self.g
+    }
+
+    /// Returns the group order n.
+    pub fn order(&self) -> &T {
+        &self.n
+    }
+
+    /// Derives the x-only public key of a 32-byte secret key.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if the secret key is 0 or ≥ n.
+    pub fn public_key(&self, secret: &[u8; 32]) -> Result<[u8; 32], EccError> {
+        let (_, p) = self.key_pair(secret)?;
+        Ok(self.bytes(&p.x))
+    }
+
+    /// Signs `message` with fresh auxiliary randomness from the thread-local RNG.
+    ///
+    /// # Errors
+    ///
+    /// See [`Bip340::sign_with_aux`].
+    pub fn sign(&self, secret: &[u8; 32], message: &[u8]) -> Result<[u8; 64], EccError> {
+        let mut aux = [0u8; 32];
+        rand::rng().fill(&mut aux);
+        self.sign_with_aux(secret, message, &aux)
+    }
+
+    /// Signs `message` with the given auxiliary randomness; all-zero `aux`
+    /// gives deterministic signatures.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if the secret key is 0 or ≥ n, and
+    /// `EccError::NonInvertible` if the derived nonce is 0 (negligibly rare).
+    pub fn sign_with_aux(&self, secret: &[u8; 32], message: &[u8], aux: &[u8; 32]) -> Result<[u8; 64], EccError> {
+        let (d, p) = self.key_pair(secret)?;
+        let p_bytes = self.bytes(&p.x);
+        // t = bytes(d) xor hash_BIP0340/aux(a).
+        let mut t = self.bytes(d.value());
+        for (byte, mask) in t.iter_mut().zip(tagged_hash("BIP0340/aux", &[aux])) {
+            *byte ^= mask;
+        }
+        let rand = tagged_hash("BIP0340/nonce", &[&t, &p_bytes, message]);
+        let k = Scalar::modulo(Utils::from_bytes_be(&rand), &self.n);
+        if k.is_zero() {
+            return Err(EccError::NonInvertible);
+        }
+        let r = self.curve.mul(&k, &self.g);
+        let k = if r.y.is_even() { k } else { -k };
+        let r_bytes = self.bytes(&r.x);
+        let e = self.challenge(&r_bytes, &p_bytes, message);
+        let s = k + e * d;
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(&r_bytes);
+        signature[32..].copy_from_slice(&self.bytes(s.value()));
+        Ok(signature)
+    }
+
+    /// Verifies a signature against an x-only public key.
+    pub fn verify(&self, public: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+        let Ok(p) = self.lift_x(public) else {
+            return false;
+        };
+        let Some((r, s)) = self.parse(signature) else {
+            return false;
+        };
+        let e = self.challenge(&signature[..32], public, message);
+        // R = s·G − e·P must not be the identity, have even y and x = r.
+        let big_r = self.curve.add(&self.curve.mul(&s, &self.g), &self.curve.mul(&-e, &p));
+        !big_r.is_identity() && big_r.y.is_even() && big_r.x == r
+    }
+
+    /// Verifies a batch of (public key, message, signature) triples at once.
+    ///
+    /// Checks (s₁ + a₂s₂ + … + aᵤsᵤ)·G = R₁ + a₂R₂ + … + aᵤRᵤ + e₁P₁ + (a₂e₂)P₂ + … + (aᵤeᵤ)Pᵤ
+    /// for random a₂, …, aᵤ in [1, n), which holds with overwhelming
+    /// probability only if every signature is valid. Returns true for an empty batch.
+    pub fn verify_batch(&self, batch: &[(&[u8; 32], &[u8], &[u8; 64])]) -> bool {
+        let mut left = Scalar::modulo(T::zero(), &self.n);
+        let mut right = Point::identity();
+        for (i, (public, message, signature)) in batch.iter().enumerate() {
+            let Ok(p) = self.lift_x(&public[..]) else {
+                return false;
+            };
+            let Some((_, s)) = self.parse(signature) else {
+                return false;
+            };
+            let Ok(r) = self.lift_x(&signature[..32]) else {
+                return false;
+            };
+            let e = self.challenge(&signature[..32], &public[..], message);
+            let a = if i == 0 { Scalar::modulo(T::one(), &self.n) } else { Scalar::random(&self.n) };
+            left = left + &a * &s;
+            right = self.curve.add(&right, &self.curve.mul(&a, &r));
+            right = self.curve.add(&right, &self.curve.mul(&(a * e), &p));
+        }
+        self.curve.mul(&left, &self.g) == right
+    }
+
+    /// Returns the secret scalar d, negated if needed, and the point P = d'·G
+    /// for the secret key d', where d·G has even y.
+    fn key_pair(&self, secret: &[u8; 32]) -> Result<(Scalar<T>, Point<T>), EccError> {
+        let d: T = Utils::from_bytes_be(secret);
+        if d.is_zero() || d >= self.n {
+            return Err(EccError::InvalidParameters("the secret key must be in [1, n)"));
+        }
+        let d = Scalar::modulo(d, &self.n);
+        let p = self.curve.mul(&d, &self.g);
+        Ok(if p.y.is_even() { (d, p) } else { (-d, p) })
+    }
+
+    /// The challenge e = int(hash_BIP0340/challenge(R.x || P.x || m)) mod n.
+    fn challenge(&self, r: &[u8], p: &[u8], message: &[u8]) -> Scalar<T> {
+        Scalar::modulo(Utils::from_bytes_be(&tagged_hash("BIP0340/challenge", &[r, p, message])), &self.n)
+    }
+
+    /// Splits a signature into r < q and s < n.
+    fn parse(&self, signature: &[u8; 64]) -> Option<(T, Scalar<T>)> {
+        let r: T = Utils::from_bytes_be(&signature[..32]);
+        let s: T = Utils::from_bytes_be(&signature[32..]);
+        if r >= self.curve.q || s >= self.n {
+            return None;
+        }
+        Some((r, Scalar::modulo(s, &self.n)))
+    }
+
+    /// lift_x: the point with x-coordinate `x` and even y.
+    fn lift_x(&self, x: &[u8]) -> Result<Point<T>, EccError> {
+        let (p, neg_p) = self.curve.at(Utils::from_bytes_be(x))?;
+        Ok(if p.y.is_even() { p } else { neg_p })
+    }
+
+    /// Encodes an integer below 2^256 as 32 big-endian bytes.
+    fn bytes(&self, value: &T) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        out.copy_from_slice(&Utils::to_bytes_be(value, 32));
+        out
+    }
+}
+
+impl<T> Default for Bip340<T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Display
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,504 @@
+use num_traits::{FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use std::ops::{BitAnd, Shr, Shl};
+use std::sync::Arc;
+use crate::error::EccError;
+use crate::field::{Fp, PrimeField};
+use crate::utils::Utils;
+
+/// A polynomial over F_q, coefficients from the constant term up, without
+/// trailing zeros (the zero polynomial is empty).
+type Poly<T> = Vec<Fp<T>>;
+
+/// A point (X(x), Y(x)*y) of the curve over F_q[x]/(h), or `None` for the identity.
+type TorsionPoint<T> = Option<(Poly<T>, Poly<T>)>;
+
+/// Counts the points of y² = x³ + a*x + b over F_q, including the identity,
+/// with Schoof's algorithm.
+///
+/// The trace t = q + 1 − #E satisfies π² − t*π + q = 0 for the Frobenius π.
+/// t mod 2 is read off the roots of x³ + a*x + b; for odd primes ℓ the
+/// relation is evaluated on the ℓ-torsion, i.e. on points with coordinates in
+/// F_q[x]/(ψ_ℓ), where ψ_ℓ is the ℓ-th division polynomial. Once the product
+/// of the primes exceeds 4√q, the Chinese remainder theorem and the Hasse bound
+/// |t| ≤ 2√q fix t. The work is polynomial in log q, but the division
+/// polynomials have degree (ℓ² − 1)/2, so fields of a few hundred bits take a
+/// long time.
+///
+/// # Errors
+///
+/// Returns `EccError::InvalidParameters` if q is not prime, and
+/// `EccError::OrderNotFound` if the trace modulo some ℓ cannot be determined,
+/// which only happens for a singular curve.
+pub fn count_points<T>(field: &Arc<PrimeField<T>>, a: &T, b: &T) -> Result<T, EccError>
+where
+    T: Clone
+    + From<u8>
+    + Integer
+    + FromPrimitive
+    + ToPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let q = field.modulus().clone();
+    if !Utils::is_probable_prime(&q) {
+        return Err(EccError::InvalidParameters("Schoof's algorithm needs a prime field"));
+    }
+    let (a, b) = (field.element(a.clone()), field.element(b.clone()));
+    let f = trim(vec![b.clone(), a.clone(), field.zero(), field.one()]);
+
+    // Primes ℓ ≠ q whose product exceeds 4√q.
+    let bound = T::from(16u8) * q.clone();
+    let mut product = T::from(2u8);
+    let mut primes = Vec::new();
+    let mut l = 3u32;
+    while product.clone() * product.clone() <= bound {
+        let prime = T::from_u32(l).unwrap();
+        if Utils::is_probable_prime(&prime) && prime != q {
+            product = product * prime;
+            primes.push(l);
+        }
+        l += 2;
+    }
+    let psi = division_polynomials(field, &f, &a, &b, primes.last().copied().unwrap_or(2) as usize);
+
+    // t ≡ #E (mod 2), and #E is even exactly when f has a root in F_q.
+    let x = vec![field.zero(), field.one()];
+    let xq = Ring::new(f.clone()).pow(&x, &q);
+    let mut trace = if gcd(sub(&xq, &x), f.clone()).len() > 1 { T::zero() } else { T::one() };
+    let mut modulus = T::from(2u8);
+    for l in primes {
+        let tau = T::from_u32(trace_mod(&f, &a, psi[l as usize].clone(), &q, l)?).unwrap();
+        let prime = T::from_u32(l).unwrap();
+        // Lift t from modulo M to modulo M*ℓ.
+        while trace.mod_floor(&prime) != tau {
+            trace = trace + modulus.clone();
+        }
+        modulus = modulus * prime;
+    }
+    if trace.clone() + trace.clone() > modulus {
+        trace = trace - modulus;
+    }
+    Ok(q + T::one() - trace)
+}
+
+/// The trace of Frobenius modulo an odd prime ℓ, from the points over
+/// F_q[x]/(ψ_ℓ).
+///
+/// When a denominator turns out to share a factor with the modulus, the work
+/// is restarted modulo that factor; its roots are still x-coordinates of
+/// ℓ-torsion points, on which the Frobenius relation holds just the same.
+fn trace_mod<T>(f: &Poly<T>, a: &Fp<T>, psi: Poly<T>, q: &T, l: u32) -> Result<u32, EccError>
+where
+    T: Clone
+    + From<u8>
+    + Integer
+    + FromPrimitive
+    + ToPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let mut h = monic(psi);
+    loop {
+        match trace_mod_factor(f, a, &h, q, l) {
+            Ok(tau) => return tau.ok_or(EccError::OrderNotFound),
+            Err(factor) if factor.len() < h.len() => h = factor,
+            Err(_) => return Err(EccError::OrderNotFound),
+        }
+    }
+}
+
+/// Finds τ with π²(P) + q*P = τ*π(P) for the points P with x-coordinate a
+/// root of h, or returns a proper factor of h found along the way.
+fn trace_mod_factor<T>(f: &Poly<T>, a: &Fp<T>, h: &Poly<T>, q: &T, l: u32) -> Result<Option<u32>, Poly<T>>
+where
+    T: Clone
+    + From<u8>
+    + Integer
+    + FromPrimitive
+    + ToPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let ring = Ring::new(h.clone());
+    let field = Arc::clone(h[0].field());
+    let curve = Torsion { f: ring.reduce(f.clone()), a: a.clone(), ring };
+    let x = curve.ring.reduce(vec![field.zero(), field.one()]);
+    let one = vec![field.one()];
+    let two = T::from(2u8);
+
+    // π(x, y) = (x^q, y^q) with y^q = f^((q − 1)/2)*y, and π² = π∘π.
+    let xq = curve.ring.pow(&x, q);
+    let yq = curve.ring.pow(&curve.f, &((q.clone() - T::one()) / two));
+    let xq2 = curve.ring.pow(&xq, q);
+    let yq2 = curve.ring.mul(&yq, &curve.ring.pow(&yq, q));
+    let pi: TorsionPoint<T> = Some((xq, yq));
+
+    let qbar = q.mod_floor(&T::from_u32(l).unwrap()).to_u32().unwrap();
+    let qp = curve.multiple(&Some((x, one)), qbar)?;
+    let s = curve.add(&Some((xq2, yq2)), &qp)?;
+    if s.is_none() {
+        // t*π(P) = 0 with π(P) ≠ 0.
+        return Ok(Some(0));
+    }
+    let mut r = pi.clone();
+    for tau in 1..l {
+        if r == s {
+            return Ok(Some(tau));
+        }
+        r = curve.add(&r, &pi)?;
+    }
+    Ok(None)
+}
+
+/// ψ_n for 0 ≤ n ≤ max (at least up to 4), with the factor 2y dropped from the
+/// even ones, so that all of them are polynomials in x alone.
+fn division_polynomials<T>(field: &Arc<PrimeField<T>>, f: &Poly<T>, a: &Fp<T>, b: &Fp<T>, max: usize) -> Vec<Poly<T>>
+where
+    T: Clone
+    + From<u8>
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let c = |n: u8| field.element(T::from(n));
+    let (aa, ab, bb) = (a * a, a * b, b * b);
+    let zero = field.zero();
+    let mut psi = vec![
+        vec![],
+        vec![field.one()],
+        vec![field.one()],
+        // 3x⁴ + 6ax² + 12bx − a²
+        trim(vec![-&aa, c(12) * b, c(6) * a, zero.clone(), c(3)]),
+        // 2(x⁶ + 5ax⁴ + 20bx³ − 5a²x² − 4abx − 8b² − a³)
+        trim(vec![
+            -(c(16) * &bb + (&aa * a).double()),
+            -(c(8) * ab),
+            -(c(10) * &aa),
+            c(40) * b,
+            c(10) * a,
+            zero,
+            c(2),
+        ]),
+    ];
+    // (2y)⁴ = 16f²
+    let f16 = scale(&mul(f, f), &c(16));
+    for n in 5..=max {
+        let m = n / 2;
+        let next = if n % 2 == 1 {
+            let left = mul(&psi[m + 2], &cube(&psi[m]));
+            let right = mul(&psi[m - 1], &cube(&psi[m + 1]));
+            if m % 2 == 0 {
+                sub(&mul(&f16, &left), &right)
+            } else {
+                sub(&left, &mul(&f16, &right))
+            }
+        } else {
+            let left = mul(&psi[m + 2], &mul(&psi[m - 1], &psi[m - 1]));
+            let right = mul(&psi[m - 2], &mul(&psi[m + 1], &psi[m + 1]));
+            mul(&psi[m], &sub(&left, &right))
+        };
+        psi.push(next);
+    }
+    psi
+}
+
+/// The ring F_q[x]/(h) for a monic h.
+struct Ring<T> {
+    h: Poly<T>,
+}
+
+impl<T> Ring<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    fn new(h: Poly<T>) -> Self {
+        Ring { h: monic(h) }
+    }
+
+    fn reduce(&self, p: Poly<T>) -> Poly<T> {
+        divrem(p, &self.h).1
+    }
+
+    fn mul(&self, a: &Poly<T>, b: &Poly<T>) -> Poly<T> {
+        self.reduce(mul(a, b))
+    }
+
+    /// p^e by square-and-multiply.
+    fn pow(&self, p: &Poly<T>, e: &T) -> Poly<T> {
+        let two = T::one() + T::one();
+        let mut result = self.reduce(vec![self.h[0].field().one()]);
+        let mut base = self.reduce(p.clone());
+        let mut e = e.clone();
+        while e > T::zero() {
+            if e.is_odd() {
+                result = self.mul(&result, &base);
+            }
+            e = e / two.clone();
+            base = self.mul(&base, &base);
+        }
+        result
+    }
+
+    /// The inverse of p, or the monic gcd of p and h if there is none.
+    fn inverse(&self, p: &Poly<T>) -> Result<Poly<T>, Poly<T>> {
+        let (mut r0, mut r1) = (self.h.clone(), p.clone());
+        let (mut s0, mut s1): (Poly<T>, Poly<T>) = (vec![], vec![self.h[0].field().one()]);
+        while !r1.is_empty() {
+            let (quotient, rest) = divrem(r0, &r1);
+            let s = sub(&s0, &mul(&quotient, &s1));
+            (r0, r1) = (r1, rest);
+            (s0, s1) = (s1, s);
+        }
+        if r0.len() > 1 {
+            return Err(monic(r0));
+        }
+        let inv = r0[0].inverse().expect("r0 is a non-zero constant");
+        Ok(self.reduce(scale(&s0, &inv)))
+    }
+}
+
+/// Curve arithmetic on points over F_q[x]/(h); the y-coordinate Y*y is stored
+/// as Y, and y² is replaced by f.
+struct Torsion<T> {
+    ring: Ring<T>,
+    f: Poly<T>,
+    a: Fp<T>,
+}
+
+impl<T> Torsion<T>
+where
+    T: Clone
+    + From<u8>
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    /// Adds two points, or returns a proper factor of h if the points agree
+    /// above some roots of h but not above others.
+    fn add(&self, p: &TorsionPoint<T>, q: &TorsionPoint<T>) -> Result<TorsionPoint<T>, Poly<T>> {
+        let (Some((x1, y1)), Some((x2, y2))) = (p, q) else {
+            return Ok(if p.is_none() { q.clone() } else { p.clone() });
+        };
+        let ring = &self.ring;
+        let lambda = if x1 == x2 {
+            if y1 != y2 {
+                if add(y1, y2).is_empty() {
+                    return Ok(None);
+                }
+                // y1 = ±y2 above every root, with both signs occurring.
+                return Err(ring.inverse(&sub(y1, y2)).err().unwrap_or_else(|| ring.h.clone()));
+            }
+            // λ = (3x² + a)/(2y) = (3X² + a)/(2Y*f) * y
+            let field = self.a.field();
+            let three = field.element(T::from(3u8));
+            let numerator = ring.reduce(add(&scale(&ring.mul(x1, x1), &three), std::slice::from_ref(&self.a)));
+            let denominator = ring.mul(&scale(y1, &field.element(T::from(2u8))), &self.f);
+            ring.mul(&numerator, &ring.inverse(&denominator)?)
+        } else {
+            // λ = (y2 − y1)/(x2 − x1) = (Y2 − Y1)/(X2 − X1) * y
+            ring.mul(&sub(y2, y1), &ring.inverse(&sub(x2, x1))?)
+        };
+        // x3 = λ² − x1 − x2 with y² = f, and y3 = λ(x1 − x3) − y1.
+        let x3 = sub(&sub(&ring.mul(&ring.mul(&lambda, &lambda), &self.f), x1), x2);
+        let y3 = sub(&ring.mul(&lambda, &sub(x1, &x3)), y1);
+        Ok(Some((x3, y3)))
+    }
+
+    /// k*p by double-and-add.
+    fn multiple(&self, p: &TorsionPoint<T>, k: u32) -> Result<TorsionPoint<T>, Poly<T>> {
+        let mut result = None;
+        for bit in (0..u32::BITS - k.leading_zeros()).rev() {
+            result = self.add(&result, &result)?;
+            if k >> bit & 1 == 1 {
+                result = self.add(&result, p)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn trim<T>(mut p: Poly<T>) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    while p.last().is_some_and(Fp::is_zero) {
+        p.pop();
+    }
+    p
+}
+
+fn add<T>(a: &[Fp<T>], b: &[Fp<T>]) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = long.to_vec();
+    for (s, c) in sum.iter_mut().zip(short) {
+        *s = &*s + c;
+    }
+    trim(sum)
+}
+
+fn sub<T>(a: &[Fp<T>], b: &[Fp<T>]) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    let negated: Poly<T> = b.iter().map(|c| -c).collect();
+    add(a, &negated)
+}
+
+fn scale<T>(p: &[Fp<T>], c: &Fp<T>) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    trim(p.iter().map(|v| v * c).collect())
+}
+
+fn mul<T>(a: &[Fp<T>], b: &[Fp<T>]) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut product = vec![a[0].field().zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] = &product[i + j] + x * y;
+        }
+    }
+    trim(product)
+}
+
+fn cube<T>(p: &[Fp<T>]) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    mul(p, &mul(p, p))
+}
+
+/// Quotient and remainder of a by a non-zero b.
+fn divrem<T>(mut a: Poly<T>, b: &[Fp<T>]) -> (Poly<T>, Poly<T>)
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    if a.len() < b.len() {
+        return (vec![], a);
+    }
+    let lead = b[b.len() - 1].inverse().expect("the divisor is non-zero");
+    let shift = a.len() - b.len();
+    let mut quotient = vec![lead.field().zero(); shift + 1];
+    for i in (0..=shift).rev() {
+        let c = &a[i + b.len() - 1] * &lead;
+        if c.is_zero() {
+            continue;
+        }
+        for (j, d) in b.iter().enumerate() {
+            a[i + j] = &a[i + j] - &c * d;
+        }
+        quotient[i] = c;
+    }
+    a.truncate(b.len() - 1);
+    (trim(quotient), trim(a))
+}
+
+fn monic<T>(p: Poly<T>) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    match p.last() {
+        Some(lead) => {
+            let inv = lead.inverse().expect("the leading coefficient is non-zero");
+            scale(&p, &inv)
+        }
+        None => p,
+    }
+}
+
+/// The monic greatest common divisor.
+fn gcd<T>(mut a: Poly<T>, mut b: Poly<T>) -> Poly<T>
+where
+    T: Clone
+    + Integer
+    + FromPrimitive
+    + Shl<u32, Output = T>
+    + Shr<u32, Output = T>
+    + BitAnd<Output = T>
+    + std::fmt::Debug,
+{
+    while !b.is_empty() {
+        let rest = divrem(a, &b).1;
+        (a, b) = (b, rest);
+    }
+    monic(a)
+}
@@ -0,0 +1,274 @@
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::curves::check_generator;
+use crate::ecc::EllipticCurve;
+use crate::edwards_curve::EdwardsCurve;
+use crate::error::EccError;
+use crate::montgomery_curve::MontgomeryCurve;
+use crate::point::Point;
+use crate::twisted_curve::TwistedCurve;
+use crate::weierstrass_ecc::WeierstrassECC;
+
+/// The equation of a curve, and the type it is loaded as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Model {
+    /// y² = x³ + a*x + b, loaded as [`WeierstrassECC`].
+    Weierstrass,
+    /// b*y² = x³ + a*x² + x, loaded as [`MontgomeryCurve`].
+    Montgomery,
+    /// a*x² + y² = 1 + b*x²*y², loaded as [`EdwardsCurve`].
+    Edwards,
+    /// a*x² + y² = 1 + b*x²*y², loaded as [`TwistedCurve`].
+    TwistedEdwards,
+}
+
+/// How the parameters of a curve were chosen, in the sense of SafeCurves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Rigidity {
+    /// Fully explained by a simple, published procedure (Curve25519, Ed448-Goldilocks).
+    FullyRigid,
+    /// Derived from public constants but with arbitrary choices left (Brainpool).
+    SomewhatRigid,
+    /// Generated from unexplained seeds or values (NIST P-curves).
+    Manipulable,
+}
+
+/// An integer in a curve specification.
+///
+/// Small values may be written as plain integers; anything else is a string in
+/// decimal or, with a `0x` prefix, hexadecimal, optionally preceded by `-`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Number {
+    Int(i64),
+    Text(String),
+}
+
+impl From<i64> for Number {
+    fn from(n: i64) -> Self {
+        Number::Int(n)
+    }
+}
+
+impl From<&str> for Number {
+    fn from(s: &str) -> Self {
+        Number::Text(s.to_string())
+    }
+}
+
+impl Number {
+    /// Parses the number into `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` if the text is not a decimal or `0x`
+    /// hexadecimal integer.
+    pub fn parse<T>(&self) -> Result<T, EccError>
+    where
+        T: Integer
+        + FromPrimitive,
+    {
+        let invalid = EccError::Encoding("numbers must be decimal or 0x-prefixed hexadecimal");
+        let text = match self {
+            Number::Int(n) => return T::from_i64(*n).ok_or(invalid),
+            Number::Text(text) => text.trim(),
+        };
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let (radix, digits) = match digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X")) {
+            Some(hex) => (16, hex),
+            None => (10, digits),
+        };
+        // from_str_radix would accept a second sign.
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(invalid);
+        }
+        let value = T::from_str_radix(digits, radix).map_err(|_| invalid)?;
+        Ok(if negative { T::zero() - value } else { value })
+    }
+}
+
+/// The affine coordinates of a generator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeneratorSpec {
+    pub x: Number,
+    pub y: Number,
+}
+
+/// A serializable description of a curve with a generator, read from and
+/// written to TOML or JSON:
+///
+/// ```toml
+/// name = "toy"
+/// model = "weierstrass"
+/// p = 17
+/// a = 2
+/// b = 3
+/// order = 11
+/// cofactor = 2
+///
+/// [generator]
+/// x = 3
+/// y = 6
+/// ```
+///
+/// The coefficients `a` and `b` are read according to the [`Model`]; for the
+/// Edwards models `b` is d. Coefficients may be negative and are reduced mod p,
+/// while the generator coordinates must already lie in [0, p).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurveSpec {
+    pub name: String,
+    /// Object identifier in dotted form, e.g. "1.2.840.10045.3.1.7".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oid: Option<String>,
+    pub model: Model,
+    /// The prime modulus of the base field.
+    pub p: Number,
+    pub a: Number,
+    pub b: Number,
+    /// The order n of the generator.
+    pub order: Number,
+    /// The cofactor h, so that the curve has h*n points.
+    pub cofactor: Number,
+    /// How the parameters were generated, if known; not checked when loading.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rigidity: Option<Rigidity>,
+    pub generator: GeneratorSpec,
+}
+
+/// A curve built from a [`CurveSpec`].
+pub struct LoadedCurve<T> {
+    pub name: String,
+    pub oid: Option<String>,
+    pub curve: Box<dyn EllipticCurve<T>>,
+    pub generator: Point<T>,
+    pub order: T,
+    pub cofactor: T,
+}
+
+impl CurveSpec {
+    /// Reads a specification from TOML.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` if the text is not a valid specification.
+    pub fn from_toml(text: &str) -> Result<Self, EccError> {
+        toml::from_str(text).map_err(|_| EccError::Encoding("not a valid TOML curve specification"))
+    }
+
+    /// Writes the specification as TOML.
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("curve specifications serialize to TOML")
+    }
+
+    /// Reads a specification from JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` if the text is not a valid specification.
+    pub fn from_json(text: &str) -> Result<Self, EccError> {
+        serde_json::from_str(text).map_err(|_| EccError::Encoding("not a valid JSON curve specification"))
+    }
+
+    /// Writes the specification as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("curve specifications serialize to JSON")
+    }
+
+    /// Builds the curve and checks it.
+    ///
+    /// # Errors
+    ///
+    /// - `EccError::Encoding` if a number cannot be parsed;
+    /// - `EccError::InvalidParameters` if the curve is singular (4a³ + 27b² ≡ 0,
+    ///   A² ≡ 4, or a = d) or otherwise rejected by its constructor, the
+    ///   generator coordinates are not in [0, p), the order or cofactor is not
+    ///   positive, h*n is outside the Hasse interval |p + 1 − h*n| ≤ 2√p, or
+    ///   the order of G is not exactly n;
+    /// - `EccError::NotOnCurve` if the generator is not a finite point of the curve.
+    pub fn load<T>(&self) -> Result<LoadedCurve<T>, EccError>
+    where
+        T: Zero
+        + One
+        + From<u8>
+        + Clone
+        + PartialEq
+        + PartialOrd
+        + FromPrimitive
+        + ToPrimitive
+        + Integer
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Rem<Output = T>
+        + Div<Output = T>
+        + BitAnd<Output = T>
+        + std::fmt::Display
+        + Shr<u32, Output = T>
+        + Shl<u32, Output = T>
+        + std::fmt::Debug
+        + 'static,
+    {
+        let Parameters { p, a, b, order, cofactor } = self.parameters::<T>()?;
+        // Hasse: |p + 1 − h*n| ≤ 2√p, squared to stay in the integers.
+        let trace = p.clone() + T::one() - cofactor.clone() * order.clone();
+        if trace.clone() * trace > T::from(4u8) * p.clone() {
+            return Err(EccError::InvalidParameters("h*n is outside the Hasse interval"));
+        }
+        let (x, y): (T, T) = (self.generator.x.parse()?, self.generator.y.parse()?);
+        if x < T::zero() || x >= p || y < T::zero() || y >= p {
+            return Err(EccError::InvalidParameters("the generator coordinates must be in [0, p)"));
+        }
+        let curve: Box<dyn EllipticCurve<T>> = match self.model {
+            Model::Weierstrass => Box::new(WeierstrassECC::try_new(a, b, p)?),
+            Model::Montgomery => {
+                // MontgomeryCurve accepts A = ±2 for experiments; a loaded curve must be smooth.
+                if (a.clone() * a.clone()).mod_floor(&p) == T::from(4u8).mod_floor(&p) {
+                    return Err(EccError::InvalidParameters("the curve is singular: A² ≡ 4 mod p"));
+                }
+                Box::new(MontgomeryCurve::try_new(a, b, p, order.clone())?)
+            }
+            Model::Edwards => Box::new(EdwardsCurve::try_new(a, b, p)?),
+            Model::TwistedEdwards => Box::new(TwistedCurve::try_new(a, b, p, order.clone())?),
+        };
+        let generator = Point::new(x, y);
+        check_generator(curve.as_ref(), &generator, &order)?;
+        Ok(LoadedCurve { name: self.name.clone(), oid: self.oid.clone(), curve, generator, order, cofactor })
+    }
+
+    /// Parses p, the coefficients reduced mod p, the order and the cofactor.
+    pub(crate) fn parameters<T>(&self) -> Result<Parameters<T>, EccError>
+    where
+        T: Integer
+        + Clone
+        + FromPrimitive,
+    {
+        let p: T = self.p.parse()?;
+        if p <= T::from_u8(2).unwrap() {
+            return Err(EccError::InvalidParameters("p must be greater than 2"));
+        }
+        let a = self.a.parse::<T>()?.mod_floor(&p);
+        let b = self.b.parse::<T>()?.mod_floor(&p);
+        let order: T = self.order.parse()?;
+        let cofactor: T = self.cofactor.parse()?;
+        if order <= T::zero() || cofactor <= T::zero() {
+            return Err(EccError::InvalidParameters("the order and cofactor must be positive"));
+        }
+        Ok(Parameters { p, a, b, order, cofactor })
+    }
+}
+
+/// The numeric parameters of a [`CurveSpec`], without the generator.
+pub(crate) struct Parameters<T> {
+    pub p: T,
+    pub a: T,
+    pub b: T,
+    pub order: T,
+    pub cofactor: T,
+}
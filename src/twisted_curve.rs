@@ -1,9 +1,15 @@
 use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
 use num_integer::Integer;
-use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr};
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
 use crate::ecc::{EllipticCurve};
+use crate::edwards;
+use crate::error::EccError;
+use crate::extended::ExtendedPoint;
+use crate::field::{Fp, PrimeField, Reduction};
+use crate::order::point_order;
 use crate::point::Point;
-use crate::utils::Utils;
+use crate::scalar::Scalar;
+use std::sync::Arc;
 
 /// Twisted Edwards curve defined by the equation:
 ///     a*x² + y² = 1 + b*x²*y²   (mod q)
@@ -11,18 +17,15 @@ use crate::utils::Utils;
 /// The curve stores:
 /// - a, b: parameters (with a ≠ 0, b ≠ 0 and a ≠ b),
 /// - q: a prime number > 2,
-/// - I: computed as 2^((q-1)/4) mod q (used in x recovery),
-/// - zero: the identity element, here (0, 1),
+/// - zero: the identity element (0, 1), as [`Point::identity`],
 /// - order: the group order.
-
-#[allow(non_snake_case)]
 pub struct TwistedCurve<T> {
     pub a: T,
     pub b: T,
     pub q: T,
-    pub I: T,
     pub zero: Point<T>,
     pub order: T,
+    field: Arc<PrimeField<T>>,
 }
 
 impl<T> TwistedCurve<T>
@@ -41,57 +44,135 @@ where
     + Mul<Output = T>
     + Rem<Output = T>
     + Div<Output = T>
-    + Copy,
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
 {
     /// Creates a new twisted Edwards curve.
     ///
     /// It asserts that q > 2, a and b are nonzero and different, and that an order is provided.
-    /// Also computes I = 2^((q-1)/4) mod q and sets the identity (zero) point as (0, 1).
+    /// The identity (zero) point is (0, 1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are rejected by [`TwistedCurve::try_new`].
     pub fn new(a: T, b: T, q: T, order: T) -> Self {
-        assert!(q > T::from(2u8));
-        assert!(a != T::zero());
-        assert!(b != T::zero());
-        assert!(a != b);
-        let one = T::one();
-        let two = T::from(2u8);
-        // Compute exponent = (q-1)/4 as a u32.
-        let exp = ((q.clone() - one.clone()) / T::from(4u8))
-            .to_u32()
-            .expect("Failed to convert exponent");
-        let i = Utils::modpow(two, exp, q.clone());
-        let zero = Point {
-            x: T::zero(),
-            y: one.clone(),
-            z: T::zero(),
-        };
-        TwistedCurve { a, b, q, I: i, zero, order }
+        Self::try_new(a, b, q, order).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Creates a new twisted Edwards curve, or returns `EccError::InvalidParameters`
+    /// if q ≤ 2, a or b is zero, a = b, or the addition law is not complete
+    /// because a is not a square or b is one.
+    pub fn try_new(a: T, b: T, q: T, order: T) -> Result<Self, EccError> {
+        if q <= T::from(2u8) {
+            return Err(EccError::InvalidParameters("q must be greater than 2"));
+        }
+        if a.is_zero() || b.is_zero() {
+            return Err(EccError::InvalidParameters("a and b must be nonzero"));
+        }
+        if a == b {
+            return Err(EccError::InvalidParameters("a and b must be different"));
+        }
+        let zero = Point::identity();
+        // Generic reduction is the fastest with BigInt; see `with_reduction` for the others.
+        let field = PrimeField::new(q.clone());
+        edwards::check_complete(&field, &a, &b)?;
+        Ok(TwistedCurve { a, b, q, zero, order, field })
+    }
+
+    /// Returns the base field F_q of the curve.
+    pub fn field(&self) -> &Arc<PrimeField<T>> {
+        &self.field
+    }
+
+    /// Selects how products in the base field are reduced. The curve starts with
+    /// [`Reduction::Generic`]; the other strategies, including the special forms
+    /// found by [`Reduction::detect`], are opt-in.
+    pub fn with_reduction(mut self, reduction: Reduction) -> Self {
+        self.field = PrimeField::with_reduction(self.q.clone(), reduction);
+        self
+    }
+
+    /// Lifts an integer into the base field.
+    fn fp(&self, v: &T) -> Fp<T> {
+        self.field.element(v.clone())
+    }
+
+    /// Converts an affine point to extended coordinates (x : y : 1 : x*y); the
+    /// identity becomes (0 : 1 : 1 : 0).
+    pub fn to_extended(&self, p: &Point<T>) -> ExtendedPoint<T> {
+        if p.is_identity() {
+            return ExtendedPoint::from_affine(self.field.zero(), self.field.one());
+        }
+        ExtendedPoint::from_affine(self.fp(&p.x), self.fp(&p.y))
+    }
+
+    /// Converts an extended point back to an affine point, with a single inversion.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::NonInvertible` if Z = 0, which cannot happen for
+    /// points reached by the complete addition law checked in [`TwistedCurve::try_new`].
+    pub fn to_affine(&self, p: &ExtendedPoint<T>) -> Result<Point<T>, EccError> {
+        let (x, y) = p.to_affine()?;
+        Ok(self.point(x, y))
+    }
+
+    /// Builds the point (x, y), returning the neutral element (0, 1) as the identity.
+    fn point(&self, x: Fp<T>, y: Fp<T>) -> Point<T> {
+        edwards::point(x, y)
+    }
+
+    /// Adds two points in extended coordinates without any inversion.
+    pub fn extended_add(&self, p: &ExtendedPoint<T>, q: &ExtendedPoint<T>) -> ExtendedPoint<T> {
+        p.add(q, &self.fp(&self.a), &self.fp(&self.b))
+    }
+
+    /// Doubles a point in extended coordinates without any inversion.
+    pub fn extended_double(&self, p: &ExtendedPoint<T>) -> ExtendedPoint<T> {
+        p.double(&self.fp(&self.a))
     }
 
     /// Recovers the x-coordinate corresponding to a given y-coordinate.
     ///
     /// The procedure is as follows:
-    /// 1. Compute: xx = (y² − 1) / (b*y² + 1)  (using a modular inverse).
-    /// 2. Compute: x = xx^((q+3)/8) mod q.
-    /// 3. If \(x^2 \not\equiv xx \bmod q\) then adjust \(x = x * I \bmod q\).
-    /// 4. Finally, ensure that \(x\) is “even” (if not, replace \(x\) with \(q - x\)).
-    pub fn xrecover(&self, y: T) -> Result<T, &'static str> {
-        let one = T::one();
-        let y2 = (y.clone() * y.clone()) % self.q.clone();
-        let numerator = (y2.clone() - one.clone()) % self.q.clone();
-        let denominator = (self.b.clone() * y2.clone() + one.clone()) % self.q.clone();
-        let inv_den = Utils::mod_inv(denominator, self.q.clone())?;
-        let xx = (numerator * inv_den) % self.q.clone();
-        let exp = ((self.q.clone() + T::from(3u8)) / T::from(8u8))
-            .to_u32()
-            .expect("Failed to convert exponent");
-        let mut x = Utils::modpow(xx.clone(), exp, self.q.clone());
-        if ((x.clone() * x.clone()) - xx.clone()) % self.q.clone() != T::zero() {
-            x = (x * self.I.clone()) % self.q.clone();
-        }
-        if (x.clone() % T::from(2u8)) != T::zero() {
-            x = self.q.clone() - x;
-        }
-        Ok(x)
+    /// 1. Compute: xx = (y² − 1) / (b*y² − a)  (using a modular inverse).
+    /// 2. Compute a square root \(x\) of xx with [`Fp::sqrt`], for any odd q.
+    /// 3. Finally, ensure that \(x\) is “even” (if not, replace \(x\) with \(q - x\)).
+    pub fn xrecover(&self, y: T) -> Result<T, EccError> {
+        edwards::xrecover(&self.field, &self.a, &self.b, y)
+    }
+
+    /// Iterates over every affine point of the curve, the identity first, by
+    /// recovering x from each y with [`TwistedCurve::xrecover`]. This takes q square
+    /// roots, so it is only meant for small curves.
+    pub fn points(&self) -> impl Iterator<Item = Point<T>> + '_ {
+        edwards::points(&self.field, &self.a, &self.b)
+    }
+
+    /// Length in bytes of an encoded point, ⌈(log₂(q) + 1) / 8⌉: 32 for
+    /// edwards25519 and 57 for Ed448-Goldilocks.
+    pub fn encoded_len(&self) -> usize {
+        edwards::encoded_len(&self.field)
+    }
+
+    /// Encodes a point as in RFC 8032 §5.1.2 / §5.2.2: y in little-endian
+    /// order, with the least significant bit of x in the most significant bit
+    /// of the last byte.
+    pub fn encode_point(&self, p: &Point<T>) -> Vec<u8> {
+        edwards::encode_point(&self.field, p)
+    }
+
+    /// Decodes a point encoded by [`TwistedCurve::encode_point`] (RFC 8032 §5.1.3 / §5.2.3).
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` for a wrong length, a non-canonical y ≥ q
+    /// or x = 0 with the sign bit set, and `EccError::NotOnCurve` if no point
+    /// has this y-coordinate.
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<Point<T>, EccError> {
+        edwards::decode_point(&self.field, &self.a, &self.b, bytes)
     }
 
     /// A version of Edwards addition tailored for the twisted curve.
@@ -106,20 +187,17 @@ where
     ///
     /// Division is performed by multiplying by the modular inverse.
     pub fn edwards_add(&self, p: &Point<T>, _q: &Point<T>) -> Point<T> {
-        let one = T::one();
-        let q = self.q.clone();
-        let x1 = p.x.clone();
-        let y1 = p.y.clone();
-        let x2 = _q.x.clone();
-        let y2 = _q.y.clone();
-        let factor = (self.b.clone() * x1.clone() * x2.clone() * y1.clone() * y2.clone()) % q.clone();
-        let denom1 = (one.clone() + factor.clone()) % q.clone();
-        let denom2 = (one.clone() - factor) % q.clone();
-        let inv_denom1 = Utils::mod_inv(denom1, q.clone()).expect("Inverse exists in edwards_add");
-        let inv_denom2 = Utils::mod_inv(denom2, q.clone()).expect("Inverse exists in edwards_add");
-        let x3 = (((x1 * y2.clone()) + (x2 * y1.clone())) * inv_denom1) % q.clone();
-        let y3 = (((y1 * y2) - (self.a.clone() * x1 * x2)) * inv_denom2) % q.clone();
-        Point { x: x3, y: y3, z: T::zero() }
+        let one = self.field.one();
+        let x1 = self.fp(&p.x);
+        let y1 = self.fp(&p.y);
+        let x2 = self.fp(&_q.x);
+        let y2 = self.fp(&_q.y);
+        let factor = self.fp(&self.b) * &x1 * &x2 * &y1 * &y2;
+        let denom1 = &one + &factor;
+        let denom2 = one - factor;
+        let x3 = (&x1 * &y2 + &x2 * &y1) / denom1;
+        let y3 = (y1 * y2 - self.fp(&self.a) * x1 * x2) / denom2;
+        self.point(x3, y3)
     }
 }
 
@@ -139,32 +217,37 @@ where
     + Mul<Output = T>
     + Rem<Output = T>
     + Div<Output = T>
-    + Copy
     + BitAnd<Output = T>
     + std::fmt::Display
-    + Shr<u32, Output = T>,
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
 {
     /// Checks whether a point \(P = (x, y)\) satisfies the twisted Edwards curve equation:
     ///     a*x² + y² = 1 + b*x²*y²  (mod q)
     ///
-    /// (Note: the Python version checks that \(-x^2 + y^2 - 1 - b*x^2*y^2 \equiv 0\), which is equivalent when \(a=-1\).)
+    /// (Note: the Python version checks that \(-x^2 + y^2 - 1 - b*x^2*y^2 \equiv 0\), which is the special case \(a=-1\).)
     fn is_valid(&self, point: &Point<T>) -> bool {
-        let x = point.x.clone();
-        let y = point.y.clone();
-        let left = (T::zero() - (x.clone() * x.clone()) + (y.clone() * y.clone()) - T::one()
-            - (self.b.clone() * x.clone() * x.clone() * y.clone() * y.clone()))
-            % self.q.clone();
-        left == T::zero()
+        if point.is_identity() {
+            return true;
+        }
+        let x2 = self.fp(&point.x).square();
+        let y2 = self.fp(&point.y).square();
+        let left = self.fp(&self.a) * &x2 + &y2 - self.field.one() - self.fp(&self.b) * x2 * y2;
+        left.is_zero()
     }
 
     /// Given a y-coordinate, returns the two corresponding points on the curve by recovering \(x\).
-    fn at(&self, y: T) -> Result<(Point<T>, Point<T>), &'static str> {
-        let x = self.xrecover(y.clone())?;
-        let neg_x = if x == T::zero() { x.clone() } else { self.q.clone() - x.clone() };
-        Ok((
-            Point { x: x.clone(), y: y.clone(), z: T::zero() },
-            Point { x: neg_x, y, z: T::zero() },
-        ))
+    fn at(&self, y: T) -> Result<(Point<T>, Point<T>), EccError> {
+        let x = self.fp(&self.xrecover(y.clone())?);
+        let neg_x = -&x;
+        let y = self.fp(&y);
+        Ok((self.point(x, y.clone()), self.point(neg_x, y)))
+    }
+
+    /// Accepts the affine form (0, 1) of the neutral element as well.
+    fn is_identity(&self, p: &Point<T>) -> bool {
+        p.is_identity() || (self.fp(&p.x).is_zero() && self.fp(&p.y) == self.field.one())
     }
 
     /// Adds two points on the curve.
@@ -172,48 +255,46 @@ where
     /// If either point is the identity, returns the other.
     /// Otherwise, uses the specialized Edwards addition for the twisted curve.
     fn add(&self, p: &Point<T>, q: &Point<T>) -> Point<T> {
-        if *p == self.zero {
+        if p.is_identity() {
             return q.clone();
         }
-        if *q == self.zero {
+        if q.is_identity() {
             return p.clone();
         }
         self.edwards_add(p, q)
     }
 
-    /// Doubles a point by adding it to itself.
+    /// Point doubling with the dedicated extended-coordinates formula (one inversion).
     fn double(&self, p: &Point<T>) -> Point<T> {
-        self.add(p, p)
+        self.to_affine(&self.extended_double(&self.to_extended(p))).expect("the addition law is complete")
     }
 
-    /// Scalar multiplication using the double-and-add algorithm.
-    fn mul(&self, mut n: i32, p: &Point<T>) -> Point<T> {
-        let zero_point = self.zero.clone();
-        let mut r = zero_point;
-        let mut m2 = p.clone();
-        while n >0 {
-            if (n.clone() & 1) == 1 {
-                r = self.add(&r, &m2);
-            }
-            n = n >> 1;
-            m2 = self.add(&m2, &m2);
+    /// Negation: −(x, y) = (−x, y).
+    fn neg(&self, p: &Point<T>) -> Point<T> {
+        if p.is_identity() {
+            return Point::identity();
         }
-        r
-    }
-
-    /// Computes the order of a point by repeatedly adding it until the identity is reached.
-    fn order(&self, g: &Point<T>) -> Result<T, &'static str> {
-        let mut order = T::one();
-        let mut current = g.clone();
-        let zero_point = self.zero.clone();
-        while current != zero_point {
-            order = order + T::one();
-            current = self.add(&current, g);
-            if order > self.q {
-                return Err("Order not found within group bounds");
+        self.point(-self.fp(&p.x), self.fp(&p.y))
+    }
+
+    /// Scalar multiplication by left-to-right double-and-add in extended
+    /// coordinates, with a single inversion at the end.
+    fn mul(&self, n: &Scalar<T>, p: &Point<T>) -> Point<T> {
+        let base = self.to_extended(p);
+        let mut r = self.to_extended(&self.zero);
+        for bit in (0..n.bits()).rev() {
+            r = self.extended_double(&r);
+            if n.bit(bit) {
+                r = self.extended_add(&r, &base);
             }
         }
-        Ok(order)
+        let r = self.to_affine(&r).expect("the addition law is complete");
+        if n.is_negative() { self.neg(&r) } else { r }
+    }
+
+    /// Computes the order of a point by baby-step giant-step, see [`point_order`].
+    fn order(&self, g: &Point<T>) -> Result<T, EccError> {
+        point_order(self, g, &self.q)
     }
 
     /// Returns a string representation of the twisted Edwards curve.
@@ -1,6 +1,7 @@
 use num_traits::{One, Zero, FromPrimitive, ToPrimitive};
 use num_integer::Integer;
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::ops::{Add, BitAnd, Div, Mul, Rem, Shl, Shr, Sub};
+use crate::error::EccError;
 
 pub struct Utils;
 
@@ -10,23 +11,184 @@ impl Utils {
 
 
     /// Provided modular exponentiation function.
-    pub fn modpow<T>(base: T, exp: u32, modulus: T) -> T
+    ///
+    /// The exponent has the same type as the base, so it is not limited to 32 bits
+    /// when `T` is a big integer.
+    pub fn modpow<T>(base: T, exp: T, modulus: T) -> T
     where
-        T: Clone + PartialEq + Zero + One + Mul<Output = T> + Rem<Output = T> + ToPrimitive,
+        T: Clone + PartialEq + Zero + One + Mul<Output = T> + Rem<Output = T> + Integer,
     {
+        let two = T::one() + T::one();
         let mut result = T::one();
-        let mut b = base % modulus.clone();
+        let mut b = base.mod_floor(&modulus);
         let mut e = exp;
-        while e > 0 {
-            if e % 2 == 1 {
+        while e > T::zero() {
+            if e.is_odd() {
                 result = (result * b.clone()) % modulus.clone();
             }
-            e /= 2;
+            e = e / two.clone();
             b = (b.clone() * b.clone()) % modulus.clone();
         }
         result
     }
 
+    /// Miller–Rabin primality test with the first twenty primes as bases.
+    ///
+    /// Exact below 3.3 * 10^24; for larger n a composite passes with
+    /// probability at most 4^-20, which is enough to vet curve parameters.
+    pub fn is_probable_prime<T>(n: &T) -> bool
+    where
+        T: Clone + PartialEq + Zero + One + Mul<Output = T> + Rem<Output = T> + Sub<Output = T> + FromPrimitive + Integer,
+    {
+        const BASES: [u8; 20] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71];
+        let one = T::one();
+        let two = T::from_u8(2).unwrap();
+        if *n < two {
+            return false;
+        }
+        for base in BASES {
+            let b = T::from_u8(base).unwrap();
+            if *n == b {
+                return true;
+            }
+            if n.is_multiple_of(&b) {
+                return false;
+            }
+        }
+        // n − 1 = d * 2^s with d odd.
+        let n_minus_1 = n.clone() - one.clone();
+        let mut d = n_minus_1.clone();
+        let mut s = 0;
+        while d.is_even() {
+            d = d / two.clone();
+            s += 1;
+        }
+        'bases: for base in BASES {
+            let mut x = Utils::modpow(T::from_u8(base).unwrap(), d.clone(), n.clone());
+            if x == one || x == n_minus_1 {
+                continue;
+            }
+            for _ in 1..s {
+                x = (x.clone() * x) % n.clone();
+                if x == n_minus_1 {
+                    continue 'bases;
+                }
+            }
+            return false;
+        }
+        true
+    }
+
+    /// Iterates over 0, 1, ..., n − 1, e.g. the elements of F_n.
+    pub fn residues<T>(n: &T) -> impl Iterator<Item = T> + use<T>
+    where
+        T: Clone + Integer,
+    {
+        let n = n.clone();
+        std::iter::successors(Some(T::zero()), |c| Some(c.clone() + T::one())).take_while(move |c| *c < n)
+    }
+
+    /// Number of bits needed to represent a non-negative integer.
+    pub fn bit_length<T>(n: &T) -> u32
+    where
+        T: Clone + Integer + Shr<u32, Output = T>,
+    {
+        let mut bits = 0;
+        let mut v = n.clone();
+        while !v.is_zero() {
+            v = v >> 1;
+            bits += 1;
+        }
+        bits
+    }
+
+    /// Integer square root ⌊√n⌋ of n ≥ 0, by Newton's method.
+    pub fn isqrt<T>(n: &T) -> T
+    where
+        T: Clone + Integer,
+    {
+        if n.is_zero() {
+            return T::zero();
+        }
+        let two = T::one() + T::one();
+        let mut x = n.clone();
+        let mut y = (x.clone() + T::one()) / two.clone();
+        while y < x {
+            x = y;
+            y = (x.clone() + n.clone() / x.clone()) / two.clone();
+        }
+        x
+    }
+
+    /// Factors n ≥ 1 into (prime, exponent) pairs in increasing order.
+    ///
+    /// Small factors are found by trial division and the rest with Pollard's
+    /// rho, so the running time grows with the square root of the second
+    /// largest prime factor; numbers of up to about 80 bits are quick.
+    pub fn factor<T>(n: &T) -> Vec<(T, u32)>
+    where
+        T: Clone + PartialEq + Zero + One + Mul<Output = T> + Rem<Output = T> + Sub<Output = T> + FromPrimitive + Integer,
+    {
+        let mut primes = Vec::new();
+        let mut rest = n.clone();
+        let mut f = T::from_u8(2).unwrap();
+        let bound = T::from_u16(1000).unwrap();
+        while f < bound && f.clone() * f.clone() <= rest {
+            while rest.is_multiple_of(&f) {
+                rest = rest / f.clone();
+                primes.push(f.clone());
+            }
+            f = f + T::one();
+        }
+        let mut composites = Vec::new();
+        if rest > T::one() {
+            composites.push(rest);
+        }
+        while let Some(m) = composites.pop() {
+            if Utils::is_probable_prime(&m) {
+                primes.push(m);
+            } else {
+                let d = Utils::pollard_rho(&m);
+                composites.push(m.clone() / d.clone());
+                composites.push(d);
+            }
+        }
+        primes.sort();
+        let mut factors: Vec<(T, u32)> = Vec::new();
+        for p in primes {
+            match factors.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => factors.push((p, 1)),
+            }
+        }
+        factors
+    }
+
+    /// Pollard's rho with Floyd cycle detection: a nontrivial divisor of an
+    /// odd composite n.
+    fn pollard_rho<T>(n: &T) -> T
+    where
+        T: Clone + Integer + FromPrimitive,
+    {
+        let mut c = T::one();
+        loop {
+            let f = |x: &T| (x.clone() * x.clone() + c.clone()).mod_floor(n);
+            let mut x = T::from_u8(2).unwrap();
+            let mut y = x.clone();
+            let mut d = T::one();
+            while d.is_one() {
+                x = f(&x);
+                y = f(&f(&y));
+                let diff = if x > y { x.clone() - y.clone() } else { y.clone() - x.clone() };
+                d = diff.gcd(n);
+            }
+            if d != *n {
+                return d;
+            }
+            c = c + T::one();
+        }
+    }
+
     /// Tonelli–Shanks algorithm: given a quadratic residue `a` modulo a prime `p`,
     /// finds an `x` such that \( x^2 \equiv a \) (mod \( p \)) (if one exists).
     ///
@@ -46,68 +208,74 @@ impl Utils {
         + ToPrimitive
         + Integer,
     {
+        let a = a.mod_floor(&p);
         // If "a" is zero, the square root is zero.
         if a == T::zero() {
             return Some(T::zero());
         }
 
-        // Convert p to u32. (This implementation assumes p is small enough.)
-        let p_u32 = p.to_u32().expect("p should convert to u32");
+        let one = T::one();
+        let two = T::from_u8(2).unwrap();
 
         // Check the Legendre symbol: a^{(p-1)/2} mod p should equal 1 for a quadratic residue.
-        let exp = (p_u32 - 1) / 2;
-        if Utils::modpow(a.clone(), exp, p.clone()) != T::one() {
+        let exp = (p.clone() - one.clone()) / two.clone();
+        if Utils::modpow(a.clone(), exp.clone(), p.clone()) != one {
             return None; // a is not a quadratic residue modulo p.
         }
 
         // Write p - 1 as q * 2^s with q odd.
-        let mut q = p_u32 - 1;
-        let mut s = 0;
-        while q % 2 == 0 {
-            q /= 2;
+        let mut q = p.clone() - one.clone();
+        let mut s = 0u32;
+        while q.is_even() {
+            q = q / two.clone();
             s += 1;
         }
 
         // Find a quadratic non-residue z (i.e. one for which the Legendre symbol is -1).
-        let mut z = T::from_u32(2).unwrap();
-        while Utils::modpow(z.clone(), exp, p.clone()) == T::one() {
-            z = z + T::one();
+        let mut z = two.clone();
+        while Utils::modpow(z.clone(), exp.clone(), p.clone()) == one {
+            z = z + one.clone();
         }
 
         // Set initial values:
         // c = z^q mod p,
         // x = a^{(q+1)/2} mod p,
         // t = a^q mod p.
-        let mut c = Utils::modpow(z, q, p.clone());
-        let mut x = Utils::modpow(a.clone(), (q + 1) / 2, p.clone());
+        let mut c = Utils::modpow(z, q.clone(), p.clone());
+        let mut x = Utils::modpow(a.clone(), (q.clone() + one.clone()) / two, p.clone());
         let mut t = Utils::modpow(a, q, p.clone());
         let mut m = s;
 
         // Main loop: adjust x until t ≡ 1 (mod p).
-        while t != T::one() {
+        while t != one {
             // Find the smallest i (0 < i < m) such that t^(2^i) ≡ 1 mod p.
             let mut i = 1;
-            while i < m && Utils::modpow(t.clone(), 1 << i, p.clone()) != T::one() {
+            let mut t2i = (t.clone() * t.clone()) % p.clone();
+            while i < m && t2i != one {
+                t2i = (t2i.clone() * t2i) % p.clone();
                 i += 1;
             }
             if i == m {
                 return None; // This should not happen if a square root exists.
             }
             // Compute b = c^(2^(m-i-1)) mod p.
-            let b = Utils::modpow(c.clone(), 1 << (m - i - 1), p.clone());
+            let mut b = c.clone();
+            for _ in 0..(m - i - 1) {
+                b = (b.clone() * b) % p.clone();
+            }
             x = (x * b.clone()) % p.clone();
             t = (t * b.clone() * b.clone()) % p.clone();
-            c = (b.clone() * b.clone()) % p.clone();
+            c = (b.clone() * b) % p.clone();
             m = i;
         }
         Some(x)
     }
 
     /// Computes the modular inverse of `a` modulo `q` using the Extended Euclidean Algorithm.
-    /// Returns an error if the inverse does not exist.
-    pub fn mod_inv<T>(a: T, q: T) -> Result<T, &'static str>
+    /// Returns `EccError::NonInvertible` if the inverse does not exist.
+    pub fn mod_inv<T>(a: T, q: T) -> Result<T, EccError>
     where
-        T: Copy
+        T: Clone
         + PartialEq
         + PartialOrd
         + Zero
@@ -116,29 +284,30 @@ impl Utils {
         + Sub<Output = T>
         + Mul<Output = T>
         + Div<Output = T>
-        + Rem<Output = T>,
+        + Rem<Output = T>
+        + Integer,
     {
         let zero = T::zero();
         let one = T::one();
 
-        let mut t = zero;
-        let mut new_t = one;
-        let mut r = q;
-        let mut new_r = a;
+        let mut t = zero.clone();
+        let mut new_t = one.clone();
+        let mut r = q.clone();
+        let mut new_r = a.mod_floor(&q);
 
         while new_r != zero {
-            let quotient = r / new_r;
-            let temp_t = new_t;
-            new_t = t - quotient * new_t;
+            let quotient = r.clone() / new_r.clone();
+            let temp_t = new_t.clone();
+            new_t = t - quotient.clone() * new_t;
             t = temp_t;
 
-            let temp_r = new_r;
+            let temp_r = new_r.clone();
             new_r = r - quotient * new_r;
             r = temp_r;
         }
 
         if r > one {
-            return Err("Inverse does not exist because a and q are not coprime");
+            return Err(EccError::NonInvertible);
         }
         if t < zero {
             t = t + q;
@@ -146,5 +315,65 @@ impl Utils {
         Ok(t)
     }
 
+    /// Parses an unsigned hexadecimal constant such as a curve parameter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` contains a character that is not a hexadecimal digit.
+    pub fn from_hex<T>(s: &str) -> T
+    where
+        T: Zero + FromPrimitive + Add<Output = T> + Shl<u32, Output = T>,
+    {
+        s.chars().fold(T::zero(), |acc, c| {
+            let digit = c.to_digit(16).expect("not a hexadecimal digit");
+            (acc << 4) + T::from_u32(digit).unwrap()
+        })
+    }
+
+    /// Reads a big-endian unsigned integer from bytes.
+    pub fn from_bytes_be<T>(bytes: &[u8]) -> T
+    where
+        T: Zero + FromPrimitive + Add<Output = T> + Shl<u32, Output = T>,
+    {
+        bytes
+            .iter()
+            .fold(T::zero(), |acc, byte| (acc << 8) + T::from_u8(*byte).unwrap())
+    }
 
-}
\ No newline at end of file
+    /// Writes a non-negative integer as exactly `len` big-endian bytes, keeping
+    /// the low-order bytes if it does not fit.
+    pub fn to_bytes_be<T>(value: &T, len: usize) -> Vec<u8>
+    where
+        T: Clone + FromPrimitive + ToPrimitive + BitAnd<Output = T> + Shr<u32, Output = T>,
+    {
+        let mask = T::from_u8(0xff).unwrap();
+        let mut v = value.clone();
+        let mut out = vec![0u8; len];
+        for byte in out.iter_mut().rev() {
+            *byte = (v.clone() & mask.clone()).to_u8().unwrap();
+            v = v >> 8;
+        }
+        out
+    }
+
+    /// Reads a little-endian unsigned integer from bytes.
+    pub fn from_bytes_le<T>(bytes: &[u8]) -> T
+    where
+        T: Zero + FromPrimitive + Add<Output = T> + Shl<u32, Output = T>,
+    {
+        bytes
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, byte| (acc << 8) + T::from_u8(*byte).unwrap())
+    }
+
+    /// Writes a non-negative integer as exactly `len` little-endian bytes.
+    pub fn to_bytes_le<T>(value: &T, len: usize) -> Vec<u8>
+    where
+        T: Clone + FromPrimitive + ToPrimitive + BitAnd<Output = T> + Shr<u32, Output = T>,
+    {
+        let mut out = Utils::to_bytes_be(value, len);
+        out.reverse();
+        out
+    }
+}
@@ -0,0 +1,350 @@
+use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
+use num_integer::Integer;
+use std::fmt;
+use std::ops::{Add, Sub, Mul, Rem, Div, BitAnd, Shr, Shl};
+use crate::error::EccError;
+use crate::scalar::Scalar;
+use crate::spec::{CurveSpec, Model, Parameters, Rigidity};
+use crate::utils::Utils;
+
+/// Minimum cost of Pollard's rho, in bits, on the curve and on its twist.
+pub const MIN_RHO_BITS: u32 = 100;
+
+/// Minimum size in bits of the CM field discriminant.
+pub const MIN_CM_BITS: u32 = 100;
+
+/// Largest accepted cofactor (8, as for Curve25519).
+pub const MAX_COFACTOR: u8 = 8;
+
+/// Embedding degrees are searched up to this bound; pairings into extensions
+/// of larger degree are far too big to attack.
+pub const EMBEDDING_SEARCH: u32 = 10_000;
+
+/// Small squarefree parts of t² − 4q are searched up to this bound.
+const CM_SEARCH: u32 = 1000;
+
+/// Trial division bound used when factoring the twist order and removing
+/// square factors from the CM discriminant.
+const TRIAL_DIVISION: u32 = 1 << 16;
+
+/// The outcome of [`validate`]: the SafeCurves-style properties of a curve.
+///
+/// The group has h*n points with trace t = q + 1 − h*n, and its quadratic twist
+/// has q + 1 + t points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report<T> {
+    pub name: String,
+    /// The field prime q.
+    pub q: T,
+    /// The subgroup order n.
+    pub order: T,
+    /// The cofactor h.
+    pub cofactor: T,
+    /// q is prime.
+    pub field_prime: bool,
+    /// The subgroup order n is prime.
+    pub order_prime: bool,
+    /// The curve equation has no singular point.
+    pub nonsingular: bool,
+    /// The trace of Frobenius t = q + 1 − h*n.
+    pub trace: T,
+    /// |t| ≤ 2√q.
+    pub hasse: bool,
+    /// h ≤ [`MAX_COFACTOR`].
+    pub cofactor_small: bool,
+    /// log₂ of the rho cost, about half the bits of n.
+    pub rho_bits: u32,
+    /// The smallest k ≤ [`EMBEDDING_SEARCH`] with q^k ≡ 1 (mod n), if any.
+    pub embedding_degree: Option<u32>,
+    /// n = q, so the curve falls to Smart's attack.
+    pub anomalous: bool,
+    /// |t² − 4q| with its square part removed: exactly when the squarefree
+    /// part is below 1000, otherwise only the square factors below 2^16. Up to
+    /// a factor 4 this bounds the CM field discriminant from above.
+    pub cm_discriminant: T,
+    /// The number of points q + 1 + t of the quadratic twist.
+    pub twist_order: T,
+    /// The largest prime factor of the twist order, if trial division and a
+    /// primality test of the cofactor-free part find it.
+    pub twist_prime: Option<T>,
+    /// Rigidity as recorded in the specification.
+    pub rigidity: Option<Rigidity>,
+}
+
+impl<T> Report<T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    /// The rho cost is at least 2^[`MIN_RHO_BITS`].
+    pub fn rho_safe(&self) -> bool {
+        self.rho_bits >= MIN_RHO_BITS
+    }
+
+    /// The embedding degree k is at least (n − 1)/100, so transfers to a
+    /// finite field (MOV, Frey–Rück) do not help.
+    pub fn mov_safe(&self) -> bool {
+        match self.embedding_degree {
+            Some(k) => T::from_u32(k).unwrap() * T::from(100u8) + T::one() >= self.order,
+            None => true,
+        }
+    }
+
+    /// The CM discriminant exceeds 2^[`MIN_CM_BITS`].
+    pub fn cm_safe(&self) -> bool {
+        Scalar::new(self.cm_discriminant.clone()).bits() > MIN_CM_BITS
+    }
+
+    /// The twist has a prime-order subgroup with rho cost at least 2^[`MIN_RHO_BITS`].
+    pub fn twist_safe(&self) -> bool {
+        match &self.twist_prime {
+            Some(p) => Scalar::new(p.clone()).bits() / 2 >= MIN_RHO_BITS,
+            None => false,
+        }
+    }
+
+    /// The parameters are known to be rigid or somewhat rigid.
+    pub fn rigid(&self) -> bool {
+        matches!(self.rigidity, Some(Rigidity::FullyRigid) | Some(Rigidity::SomewhatRigid))
+    }
+
+    /// Every check with its outcome, in the order of the SafeCurves list.
+    pub fn checks(&self) -> Vec<(&'static str, bool)> {
+        vec![
+            ("field prime", self.field_prime),
+            ("prime subgroup order", self.order_prime),
+            ("non-singular", self.nonsingular),
+            ("Hasse bound", self.hasse),
+            ("small cofactor", self.cofactor_small),
+            ("rho", self.rho_safe()),
+            ("transfer (MOV)", self.mov_safe()),
+            ("not anomalous", !self.anomalous),
+            ("CM discriminant", self.cm_safe()),
+            ("twist security", self.twist_safe()),
+            ("rigidity", self.rigid()),
+        ]
+    }
+
+    /// The names of the failed checks.
+    pub fn failures(&self) -> Vec<&'static str> {
+        self.checks().into_iter().filter(|(_, ok)| !ok).map(|(name, _)| name).collect()
+    }
+
+    /// Returns true if every check passes.
+    pub fn is_safe(&self) -> bool {
+        self.failures().is_empty()
+    }
+}
+
+impl<T> fmt::Display for Report<T>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.name)?;
+        for (name, ok) in self.checks() {
+            writeln!(f, "  [{}] {}", if ok { "ok" } else { "FAIL" }, name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks a curve specification against the SafeCurves criteria.
+///
+/// Everything is derived from q, the coefficients, n and h; the generator is
+/// not used, see [`CurveSpec::load`] for that. Primality is tested with
+/// [`Utils::is_probable_prime`].
+///
+/// # Errors
+///
+/// Returns `EccError::Encoding` if a number cannot be parsed, and
+/// `EccError::InvalidParameters` if p ≤ 2 or the order or cofactor is not positive.
+pub fn validate<T>(spec: &CurveSpec) -> Result<Report<T>, EccError>
+where
+    T: Zero
+    + One
+    + From<u8>
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + Div<Output = T>
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    let Parameters { p: q, a, b, order: n, cofactor: h } = spec.parameters::<T>()?;
+    let points = h.clone() * n.clone();
+    let q1 = q.clone() + T::one();
+    let trace = q1.clone() - points.clone();
+    let hasse = trace.clone() * trace.clone() <= T::from(4u8) * q.clone();
+    let twist_order = q1 + trace.clone();
+    Ok(Report {
+        name: spec.name.clone(),
+        field_prime: Utils::is_probable_prime(&q),
+        order_prime: Utils::is_probable_prime(&n),
+        nonsingular: !discriminant(spec.model, &a, &b, &q).is_zero(),
+        hasse,
+        cofactor_small: h <= T::from(MAX_COFACTOR),
+        rho_bits: Scalar::new(n.clone()).bits() / 2,
+        embedding_degree: embedding_degree(&q, &n),
+        anomalous: n == q,
+        cm_discriminant: squarefree_part(trace.clone() * trace.clone() - T::from(4u8) * q.clone()),
+        twist_prime: if twist_order > T::zero() { largest_prime_factor(twist_order.clone()) } else { None },
+        twist_order,
+        trace,
+        rigidity: spec.rigidity,
+        q,
+        order: n,
+        cofactor: h,
+    })
+}
+
+/// The discriminant of the curve equation up to a unit, reduced mod q:
+/// 4a³ + 27b² (Weierstrass), B(A² − 4) (Montgomery) or ad(a − d) (Edwards).
+fn discriminant<T>(model: Model, a: &T, b: &T, q: &T) -> T
+where
+    T: Clone
+    + From<u8>
+    + Integer,
+{
+    let d = match model {
+        Model::Weierstrass => {
+            T::from(4u8) * a.clone() * a.clone() * a.clone() + T::from(27u8) * b.clone() * b.clone()
+        }
+        Model::Montgomery => b.clone() * (a.clone() * a.clone() - T::from(4u8)),
+        Model::Edwards | Model::TwistedEdwards => a.clone() * b.clone() * (a.clone() - b.clone()),
+    };
+    d.mod_floor(q)
+}
+
+/// The multiplicative order of q mod n, if it is at most [`EMBEDDING_SEARCH`].
+fn embedding_degree<T>(q: &T, n: &T) -> Option<u32>
+where
+    T: Clone
+    + Integer,
+{
+    let q = q.mod_floor(n);
+    let mut power = q.clone();
+    for k in 1..=EMBEDDING_SEARCH {
+        if power.is_one() {
+            return Some(k);
+        }
+        if power.is_zero() {
+            return None;
+        }
+        power = (power * q.clone()).mod_floor(n);
+    }
+    None
+}
+
+/// The squarefree part of |d|, if it is at most [`CM_SEARCH`]; otherwise |d|
+/// divided by the squares of all integers below [`TRIAL_DIVISION`] dividing it.
+fn squarefree_part<T>(d: T) -> T
+where
+    T: Clone
+    + FromPrimitive
+    + Integer,
+{
+    let mut d = if d < T::zero() { T::zero() - d } else { d };
+    if d.is_zero() {
+        return d;
+    }
+    let mut f = T::from_u8(2).unwrap();
+    let bound = T::from_u32(TRIAL_DIVISION).unwrap();
+    while f < bound && f.clone() * f.clone() <= d {
+        let square = f.clone() * f.clone();
+        while d.is_multiple_of(&square) {
+            d = d / square.clone();
+        }
+        f = f + T::one();
+    }
+    // What is left is s*v² with v free of small factors; find s if it is small.
+    for s in 1..=CM_SEARCH {
+        let s = T::from_u32(s).unwrap();
+        if d.is_multiple_of(&s) && is_square(&(d.clone() / s.clone())) {
+            return s;
+        }
+    }
+    d
+}
+
+/// Returns true if n ≥ 0 is a perfect square.
+fn is_square<T>(n: &T) -> bool
+where
+    T: Clone
+    + Integer,
+{
+    let root = Utils::isqrt(n);
+    root.clone() * root == *n
+}
+
+/// The largest prime factor of n > 0, by trial division below
+/// [`TRIAL_DIVISION`] and a primality test of what is left, or `None` if what
+/// is left is composite.
+fn largest_prime_factor<T>(n: T) -> Option<T>
+where
+    T: Clone
+    + FromPrimitive
+    + Integer,
+{
+    let mut rest = n;
+    let mut largest = T::one();
+    let mut f = T::from_u8(2).unwrap();
+    let bound = T::from_u32(TRIAL_DIVISION).unwrap();
+    while f < bound && f.clone() * f.clone() <= rest {
+        while rest.is_multiple_of(&f) {
+            rest = rest / f.clone();
+            largest = f.clone();
+        }
+        f = f + T::one();
+    }
+    if rest.is_one() {
+        Some(largest)
+    } else if f.clone() * f <= rest && !Utils::is_probable_prime(&rest) {
+        None
+    } else {
+        // Every factor of rest exceeds every factor removed so far.
+        Some(rest)
+    }
+}
@@ -1,15 +1,35 @@
 use num_traits::{Zero, One, FromPrimitive, ToPrimitive};
-use std::ops::{Mul, Rem, Add, Sub, BitAnd, Shr, Div};
+use std::ops::{Mul, Rem, Add, Sub, BitAnd, Shr, Div, Shl};
+use std::sync::Arc;
 use num_integer::Integer;
 use crate::ecc::{EllipticCurve};
+use crate::error::EccError;
+use crate::field::{Fp, PrimeField, Reduction};
+use crate::order::point_order;
 use crate::point::Point;
+use crate::schoof::count_points;
+use crate::scalar::Scalar;
 use crate::utils::Utils;
 
+/// A point in Jacobian coordinates (X : Y : Z), standing for the affine point
+/// (X/Z², Y/Z³). The point at infinity is any triple with Z = 0.
+///
+/// Additions and doublings in this form need no field inversion; a single one is
+/// paid when converting back with [`WeierstrassECC::to_affine`]. Since the same
+/// point has many representations, compare points after `to_affine`.
+#[derive(Clone)]
+pub struct JacobianPoint<T> {
+    pub x: Fp<T>,
+    pub y: Fp<T>,
+    pub z: Fp<T>,
+}
+
 pub struct WeierstrassECC<T> {
     // elliptic curve: y² = x³ + a * x + b mod q
     pub a: T,
     pub b: T,
     pub q: T,
+    field: Arc<PrimeField<T>>,
 }
 
 impl<T> WeierstrassECC<T>
@@ -27,36 +47,306 @@ where
     + Mul<Output = T>
     + Rem<Output = T>
     + Div<Output = T>
-    + Copy,
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
 {
     /// Creates a new Weierstrass curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are rejected by [`WeierstrassECC::try_new`].
     pub fn new(a: T, b: T, q: T) -> Self {
-        assert!(q > T::from(2u8));
-        assert!(a != T::zero());
-        assert!(b != T::zero());
-        // You might want additional assertions depending on your use case.
-        WeierstrassECC { a, b, q }
+        Self::try_new(a, b, q).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Creates a new Weierstrass curve, or returns `EccError::InvalidParameters`
+    /// if q ≤ 3 or the curve is singular, i.e. 4a³ + 27b² ≡ 0 (mod q).
+    ///
+    /// a = 0 (secp256k1, BN and BLS curves) and b = 0 (y² = x³ + ax) are allowed.
+    pub fn try_new(a: T, b: T, q: T) -> Result<Self, EccError> {
+        if q <= T::from(3u8) {
+            return Err(EccError::InvalidParameters("q must be greater than 3"));
+        }
+        // Generic reduction is the fastest with BigInt; see `with_reduction` for the others.
+        let field = PrimeField::new(q.clone());
+        let (fa, fb) = (field.element(a.clone()), field.element(b.clone()));
+        let discriminant = field.element(T::from(4u8)) * fa.square() * fa + field.element(T::from(27u8)) * fb.square();
+        if discriminant.is_zero() {
+            return Err(EccError::InvalidParameters("the curve is singular: 4a³ + 27b² ≡ 0 mod q"));
+        }
+        Ok(WeierstrassECC { a, b, q, field })
+    }
+
+    /// Returns the base field F_q of the curve.
+    pub fn field(&self) -> &Arc<PrimeField<T>> {
+        &self.field
+    }
+
+    /// Selects how products in the base field are reduced. The curve starts with
+    /// [`Reduction::Generic`]; the other strategies, including the special forms
+    /// found by [`Reduction::detect`], are opt-in.
+    pub fn with_reduction(mut self, reduction: Reduction) -> Self {
+        self.field = PrimeField::with_reduction(self.q.clone(), reduction);
+        self
+    }
+
+    /// Returns the number of points #E(F_q), including the identity, computed
+    /// with Schoof's algorithm, see [`count_points`]. Unlike
+    /// [`EllipticCurve::order`] this is the order of the whole group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::InvalidParameters` if q is not prime.
+    pub fn group_order(&self) -> Result<T, EccError> {
+        count_points(&self.field, &self.a, &self.b)
+    }
+
+    /// Lifts an integer into the base field.
+    fn fp(&self, v: &T) -> Fp<T> {
+        self.field.element(v.clone())
+    }
+
+    /// Evaluates the right-hand side x³ + a*x + b.
+    fn rhs(&self, x: &Fp<T>) -> Fp<T> {
+        x.square() * x + self.fp(&self.a) * x + self.fp(&self.b)
+    }
+
+    /// Length in bytes of an encoded field element, ⌈log₂(q) / 8⌉.
+    pub fn field_len(&self) -> usize {
+        Scalar::new(self.q.clone()).bits().div_ceil(8) as usize
+    }
+
+    /// Encodes a point in SEC1 form (SEC 1 v2, §2.3.3): 0x00 for the identity,
+    /// 0x04 || x || y uncompressed, or 0x02 / 0x03 || x compressed, with the
+    /// prefix carrying the parity of y. Coordinates are big-endian field elements.
+    pub fn encode_point(&self, p: &Point<T>, compressed: bool) -> Vec<u8> {
+        if p.is_identity() {
+            return vec![0x00];
+        }
+        let p = self.normalize(p.clone());
+        let len = self.field_len();
+        let mut out = Vec::with_capacity(1 + 2 * len);
+        if compressed {
+            out.push(if p.y.is_odd() { 0x03 } else { 0x02 });
+            out.extend(Utils::to_bytes_be(&p.x, len));
+        } else {
+            out.push(0x04);
+            out.extend(Utils::to_bytes_be(&p.x, len));
+            out.extend(Utils::to_bytes_be(&p.y, len));
+        }
+        out
+    }
+
+    /// Decodes a SEC1-encoded point (SEC 1 v2, §2.3.4), recovering y from x
+    /// with a square root for compressed points.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EccError::Encoding` for an unknown prefix, a wrong length or a
+    /// coordinate ≥ q, and `EccError::NotOnCurve` if the coordinates do not
+    /// describe a point on the curve.
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<Point<T>, EccError> {
+        let len = self.field_len();
+        let Some((&prefix, rest)) = bytes.split_first() else {
+            return Err(EccError::Encoding("empty point encoding"));
+        };
+        let expected = match prefix {
+            0x00 => 0,
+            0x02 | 0x03 => len,
+            0x04 => 2 * len,
+            _ => return Err(EccError::Encoding("unknown point prefix")),
+        };
+        if rest.len() != expected {
+            return Err(EccError::Encoding("point encoding has the wrong length"));
+        }
+        if prefix == 0x00 {
+            return Ok(Point::identity());
+        }
+        let x: T = Utils::from_bytes_be(&rest[..len]);
+        if x >= self.q {
+            return Err(EccError::Encoding("x-coordinate is not below q"));
+        }
+        let y = if prefix == 0x04 {
+            let y: T = Utils::from_bytes_be(&rest[len..]);
+            if y >= self.q {
+                return Err(EccError::Encoding("y-coordinate is not below q"));
+            }
+            if self.fp(&y).square() != self.rhs(&self.fp(&x)) {
+                return Err(EccError::NotOnCurve);
+            }
+            y
+        } else {
+            let y = self.rhs(&self.fp(&x)).sqrt().ok_or(EccError::NotOnCurve)?;
+            let y = if y.value().is_odd() == (prefix == 0x03) { y.value() } else { (-y).value() };
+            // y = 0 is even, so 0x03 cannot encode it.
+            if y.is_odd() != (prefix == 0x03) {
+                return Err(EccError::Encoding("y = 0 cannot have an odd prefix"));
+            }
+            y
+        };
+        Ok(Point::new(x, y))
     }
 
     /// Normalize a point.
     ///
     /// If the point is not the identity, adjust x and y modulo q and set z to 1.
     pub fn normalize(&self, mut p: Point<T>) -> Point<T> {
-        // Define the identity point as (0,0,0)
-        let identity = Point {
-            x: T::zero(),
-            y: T::zero(),
-            z: T::zero(),
-        };
-        if p == identity {
-            p
+        if p.is_identity() {
+            Point::identity()
         } else {
-            p.x = ((p.x % self.q.clone()) + self.q.clone()) % self.q.clone();
-            p.y = ((p.y % self.q.clone()) + self.q.clone()) % self.q.clone();
+            p.x = self.fp(&p.x).value();
+            p.y = self.fp(&p.y).value();
             p.z = T::one();
             p
         }
     }
+
+    /// Converts an affine point to Jacobian coordinates (x : y : 1); the identity becomes (1 : 1 : 0).
+    pub fn to_jacobian(&self, p: &Point<T>) -> JacobianPoint<T> {
+        if p.is_identity() {
+            return self.jacobian_identity();
+        }
+        JacobianPoint {
+            x: self.fp(&p.x),
+            y: self.fp(&p.y),
+            z: self.field.one(),
+        }
+    }
+
+    /// Converts a Jacobian point back to a normalized affine point with one inversion.
+    pub fn to_affine(&self, p: &JacobianPoint<T>) -> Point<T> {
+        if p.z.is_zero() {
+            return Point::identity();
+        }
+        let z_inv = p.z.inverse().expect("Z is non-zero");
+        let z_inv2 = z_inv.square();
+        let x = &p.x * &z_inv2;
+        let y = &p.y * z_inv2 * z_inv;
+        self.normalize(Point { x: x.value(), y: y.value(), z: T::one() })
+    }
+
+    /// The point at infinity (1 : 1 : 0).
+    pub fn jacobian_identity(&self) -> JacobianPoint<T> {
+        JacobianPoint {
+            x: self.field.one(),
+            y: self.field.one(),
+            z: self.field.zero(),
+        }
+    }
+
+    /// Doubles a Jacobian point ("dbl-2007-bl", valid for any a).
+    pub fn jacobian_double(&self, p: &JacobianPoint<T>) -> JacobianPoint<T> {
+        if p.z.is_zero() || p.y.is_zero() {
+            return self.jacobian_identity();
+        }
+        let xx = p.x.square();
+        let yy = p.y.square();
+        let yyyy = yy.square();
+        let zz = p.z.square();
+        // S = 2*((X + YY)² − XX − YYYY)
+        let s = ((&p.x + &yy).square() - &xx - &yyyy).double();
+        // M = 3*XX + a*ZZ²
+        let m = xx.double() + &xx + self.fp(&self.a) * zz.square();
+        // X3 = M² − 2*S
+        let x3 = m.square() - s.double();
+        // Y3 = M*(S − X3) − 8*YYYY
+        let y3 = m * (s - &x3) - yyyy.double().double().double();
+        // Z3 = (Y + Z)² − YY − ZZ
+        let z3 = (&p.y + &p.z).square() - yy - zz;
+        JacobianPoint { x: x3, y: y3, z: z3 }
+    }
+
+    /// Adds two Jacobian points ("add-2007-bl").
+    pub fn jacobian_add(&self, p: &JacobianPoint<T>, q: &JacobianPoint<T>) -> JacobianPoint<T> {
+        if p.z.is_zero() {
+            return q.clone();
+        }
+        if q.z.is_zero() {
+            return p.clone();
+        }
+        let z1z1 = p.z.square();
+        let z2z2 = q.z.square();
+        let u1 = &p.x * &z2z2;
+        let u2 = &q.x * &z1z1;
+        let s1 = &p.y * &q.z * &z2z2;
+        let s2 = &q.y * &p.z * &z1z1;
+        if u1 == u2 {
+            return if s1 == s2 { self.jacobian_double(p) } else { self.jacobian_identity() };
+        }
+        let h = u2 - &u1;
+        let i = h.double().square();
+        let j = &h * &i;
+        let r = (s2 - &s1).double();
+        let v = u1 * i;
+        let x3 = r.square() - &j - v.double();
+        let y3 = r * (v - &x3) - (s1 * j).double();
+        let z3 = ((&p.z + &q.z).square() - z1z1 - z2z2) * h;
+        JacobianPoint { x: x3, y: y3, z: z3 }
+    }
+
+    /// Adds an affine point to a Jacobian point ("madd-2007-bl"), saving the work
+    /// that Z2 = 1 makes unnecessary.
+    pub fn mixed_add(&self, p: &JacobianPoint<T>, q: &Point<T>) -> JacobianPoint<T> {
+        if q.is_identity() {
+            return p.clone();
+        }
+        if p.z.is_zero() {
+            return self.to_jacobian(q);
+        }
+        let (x2, y2) = (self.fp(&q.x), self.fp(&q.y));
+        let z1z1 = p.z.square();
+        let u2 = x2 * &z1z1;
+        let s2 = y2 * &p.z * &z1z1;
+        if u2 == p.x {
+            return if s2 == p.y { self.jacobian_double(p) } else { self.jacobian_identity() };
+        }
+        let h = u2 - &p.x;
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = &h * &i;
+        let r = (s2 - &p.y).double();
+        let v = &p.x * i;
+        let x3 = r.square() - &j - v.double();
+        let y3 = r * (v - &x3) - (&p.y * j).double();
+        let z3 = (&p.z + h).square() - z1z1 - hh;
+        JacobianPoint { x: x3, y: y3, z: z3 }
+    }
+}
+
+impl<T> WeierstrassECC<T>
+where
+    T: Zero
+    + From<u8>
+    + One
+    + Clone
+    + PartialEq
+    + PartialOrd
+    + FromPrimitive
+    + ToPrimitive
+    + Integer
+    + Add<Output = T>
+    + Sub<Output = T>
+    + Mul<Output = T>
+    + Rem<Output = T>
+    + std::fmt::Display
+    + BitAnd<Output = T>
+    + Shr<u32, Output = T>
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
+{
+    /// Iterates over every point of the curve, the identity first, trying each
+    /// x-coordinate with [`EllipticCurve::at`]. This takes q square roots, so
+    /// it is only meant for small curves.
+    pub fn points(&self) -> impl Iterator<Item = Point<T>> + '_ {
+        let finite = Utils::residues(&self.q).flat_map(move |x| match self.at(x) {
+            Ok((p, n)) if p == n => vec![p],
+            Ok((p, n)) => vec![p, n],
+            Err(_) => vec![],
+        });
+        std::iter::once(Point::identity()).chain(finite)
+    }
 }
 
 impl<T> EllipticCurve<T> for WeierstrassECC<T>
@@ -73,162 +363,128 @@ where
     + Add<Output = T>
     + Sub<Output = T>
     + Mul<Output = T>
-    + Copy
     + Rem<Output = T>
     + std::fmt::Display
     + BitAnd<Output = T>  // for n & 1
-    + Shr<u32, Output = T>, // for n >> 1,
+    + Shr<u32, Output = T> // for n >> 1,
+    + Shl<u32, Output = T>
+    + std::fmt::Debug,
 {
 
     fn is_valid(&self, p: &Point<T>) -> bool {
-        // Treat (0,0) as the identity.
-        if p.x == T::zero() && p.y == T::zero() {
+        if p.is_identity() {
             return true;
         }
-        let lhs = (p.y.clone() * p.y.clone()) % self.q.clone();
-        let rhs = ((p.x.clone() * p.x.clone() * p.x.clone())
-            + (self.a.clone() * p.x.clone())
-            + self.b.clone())
-            % self.q.clone();
-        lhs == rhs
+        let x = self.fp(&p.x);
+        let y = self.fp(&p.y);
+        y.square() == self.rhs(&x)
     }
 
-    fn at(&self, x: T) -> Result<(Point<T>, Point<T>), &'static str> {
+    fn at(&self, x: T) -> Result<(Point<T>, Point<T>), EccError> {
         if x >= self.q.clone() {
-            return Err("x must be less than q");
+            return Err(EccError::InvalidParameters("x must be less than q"));
         }
-        let rhs = ((x.clone() * x.clone() * x.clone())
-            + (self.a.clone() * x.clone())
-            + self.b.clone())
-            % self.q.clone();
-        match Utils::tonelli_shanks(rhs, self.q.clone()) {
+        let x = self.fp(&x);
+        match self.rhs(&x).sqrt() {
             Some(y) => {
-                let neg_y = (self.q.clone() - y.clone()) % self.q.clone();
+                let neg_y = -&y;
                 // Return normalized points.
                 Ok((
-                    self.normalize(Point { x: x.clone(), y: y, z: T::one() }),
-                    self.normalize(Point { x, y: neg_y, z: T::one() }),
+                    self.normalize(Point { x: x.value(), y: y.value(), z: T::one() }),
+                    self.normalize(Point { x: x.value(), y: neg_y.value(), z: T::one() }),
                 ))
             },
-            None => Err("No square root found"),
+            None => Err(EccError::NoSquareRoot),
         }
     }
 
     fn add(&self, _p: &Point<T>, _q: &Point<T>) -> Point<T> {
-        assert_eq!(self.is_valid(_p), true);
-        assert_eq!(self.is_valid(_q), true);
+        assert!(self.is_valid(_p));
+        assert!(self.is_valid(_q));
 
-        // Define the identity point.
-        let identity = Point { x: T::zero(), y: T::zero(), z: T::zero() };
-        if *_p == identity {
+        if _p.is_identity() {
             return _q.clone();
         }
-        if *_q == identity {
+        if _q.is_identity() {
             return _p.clone();
         }
+        let (x1, y1) = (self.fp(&_p.x), self.fp(&_p.y));
+        let (x2, y2) = (self.fp(&_q.x), self.fp(&_q.y));
         // If x coordinates are equal and y differ (or y is zero), result is identity.
-        if _p.x == _q.x && (_p.y != _q.y || _p.y == T::zero()) {
-            return identity;
+        if x1 == x2 && (y1 != y2 || y1.is_zero()) {
+            return Point::identity();
         }
-        let l;
-        if _p.x == _q.x {
+        let l = if x1 == x2 {
             // Tangent case.
-            let two = T::from(2u8);
-            let inv_val = Utils::mod_inv(two * _p.y.clone(), self.q.clone())
-                .expect("Inverse should exist");
-            let three = T::from(3u8);
-            l = (((three * _p.x.clone() * _q.x.clone()) + self.a.clone()) * inv_val) % self.q.clone();
+            let three = self.fp(&T::from(3u8));
+            (three * x1.square() + self.fp(&self.a)) / y1.double()
         } else {
             // Chord case.
-            let tmp = (_q.x.clone() - _p.x.clone()) % self.q.clone();
-            let inv = Utils::mod_inv(tmp, self.q.clone()).expect("Inverse should exist");
-            l = ((_q.y - _p.y.clone()) * inv) % self.q.clone();
-        }
-        let mut res = Point { x: T::zero(), y: T::zero(), z: T::zero() };
-        res.x = ((l * l) - _p.x - _q.x.clone()) % self.q.clone();
-        res.y = (l * (_p.x - res.x.clone()) - _p.y.clone()) % self.q.clone();
+            (&y2 - &y1) / (&x2 - &x1)
+        };
+        let x3 = l.square() - &x1 - &x2;
+        let y3 = l * (x1 - &x3) - y1;
         // Normalize the result so that nonzero points have z = 1.
-        self.normalize(res)
+        self.normalize(Point { x: x3.value(), y: y3.value(), z: T::one() })
     }
 
     fn double(&self, p: &Point<T>) -> Point<T> {
-        // Define the identity point.
-        let identity = Point {
-            x: T::zero(),
-            y: T::zero(),
-            z: T::zero(),
-        };
-
         // If p is the identity, return it.
-        if *p == identity {
-            return p.clone();
+        if p.is_identity() {
+            return Point::identity();
         }
+        let x = self.fp(&p.x);
+        let y = self.fp(&p.y);
         // If y == 0, doubling yields the identity.
-        if p.y == T::zero() {
-            return identity;
+        if y.is_zero() {
+            return Point::identity();
         }
 
         // Calculate lambda = (3*x^2 + a) / (2*y) mod q.
-        let two = T::from(2u8);
-        let three = T::from(3u8);
-        let x_sq = p.x.clone() * p.x.clone();
-        let numerator = (three * x_sq + self.a.clone()) % self.q.clone();
-        let denominator = (two * p.y.clone()) % self.q.clone();
-        let inv_den = Utils::mod_inv(denominator, self.q.clone())
-            .expect("Inverse should exist for denominator in doubling");
-        let lambda = (numerator * inv_den) % self.q.clone();
+        let three = self.fp(&T::from(3u8));
+        let lambda = (three * x.square() + self.fp(&self.a)) / y.double();
 
         // x3 = lambda^2 - 2*x
-        let lambda_sq = lambda.clone() * lambda.clone();
-        let x3 = (lambda_sq - two * p.x.clone()) % self.q.clone();
+        let x3 = lambda.square() - x.double();
         // y3 = lambda*(x - x3) - y
-        let y3 = (lambda * (p.x.clone() - x3.clone()) - p.y.clone()) % self.q.clone();
+        let y3 = lambda * (x - &x3) - y;
 
         // Construct the result with z = 1 (after normalization).
         let result = Point {
-            x: x3,
-            y: y3,
+            x: x3.value(),
+            y: y3.value(),
             z: T::one(),
         };
         self.normalize(result)
     }
 
 
-    fn mul(&self, n: i32, p: &Point<T>) -> Point<T> {
-        let zero_point = Point {
-            x: T::zero(),
-            y: T::zero(),
-            z: T::zero(),
-        };
-        let mut n_c = n.clone();
-        let mut r = zero_point.clone();
-        let mut m2 = p.clone();
-        while n_c > 0 {
-            if (n_c & 1) == 1 {
-                r = self.add(&r, &m2);
-            }
-            n_c = n_c >> 1;
-            m2 = self.add(&m2, &m2);
+    /// Negation: −(x, y) = (x, −y).
+    fn neg(&self, p: &Point<T>) -> Point<T> {
+        if p.is_identity() {
+            return Point::identity();
         }
-        // Normalize the resulting point.
-        self.normalize(r)
+        let y = -self.fp(&p.y);
+        self.normalize(Point { x: p.x.clone(), y: y.value(), z: T::one() })
     }
 
-    fn order(&self, g: &Point<T>) -> Result<T, &'static str> {
-        let identity = Point { x: T::zero(), y: T::zero(), z: T::zero() };
-        if !self.is_valid(g) || *g == identity {
-            return Err("Invalid point");
-        }
-        let mut order = T::one();
-        let mut current = g.clone();
-        while current != identity {
-            order = order + T::one();
-            current = self.add(&current, g);
-            if order > self.q {
-                return Err("Point order not found within group bounds");
+    /// Scalar multiplication by left-to-right double-and-add in Jacobian coordinates,
+    /// with a single inversion at the end.
+    fn mul(&self, n: &Scalar<T>, p: &Point<T>) -> Point<T> {
+        let mut r = self.jacobian_identity();
+        for bit in (0..n.bits()).rev() {
+            r = self.jacobian_double(&r);
+            if n.bit(bit) {
+                r = self.mixed_add(&r, p);
             }
         }
-        Ok(order)
+        let r = self.to_affine(&r);
+        if n.is_negative() { self.neg(&r) } else { r }
+    }
+
+    /// Computes the order of a point by baby-step giant-step, see [`point_order`].
+    fn order(&self, g: &Point<T>) -> Result<T, EccError> {
+        point_order(self, g, &self.q)
     }
 
     fn display(&self) -> String {
@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves::{self, NamedCurve};
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::eddsa::Ed25519;
+    use KissECC::error::EccError;
+    use KissECC::point::Point;
+    use KissECC::schnorr::Bip340;
+    use num_bigint::BigInt;
+
+    /// h*n must lie in the Hasse interval |h*n − (q + 1)| ≤ 2√q.
+    fn assert_hasse(h: &BigInt, n: &BigInt, q: &BigInt) {
+        let t = h * n - (q + 1);
+        assert!(&t * &t <= q * 4);
+    }
+
+    fn assert_named<C: EllipticCurve<BigInt>>(c: &NamedCurve<C, BigInt>, q: &BigInt) {
+        assert_eq!(c.check(), Ok(()), "{}", c.name);
+        assert_hasse(&c.cofactor, &c.order, q);
+    }
+
+    #[test]
+    fn test_weierstrass_curves() {
+        for c in [
+            curves::p192(),
+            curves::p224(),
+            curves::p256(),
+            curves::p384(),
+            curves::p521(),
+            curves::secp256k1(),
+            curves::brainpool_p256r1(),
+            curves::brainpool_p384r1(),
+        ] {
+            assert_named(&c, &c.curve.q);
+            assert_eq!(c.cofactor, BigInt::from(1));
+        }
+    }
+
+    #[test]
+    fn test_montgomery_curves() {
+        let c = curves::curve25519::<BigInt>();
+        assert_named(&c, &c.curve.q);
+        assert_eq!(c.cofactor, BigInt::from(8));
+        let c = curves::curve448::<BigInt>();
+        assert_named(&c, &c.curve.q);
+        assert_eq!(c.cofactor, BigInt::from(4));
+    }
+
+    #[test]
+    fn test_edwards_curves() {
+        let c = curves::ed25519::<BigInt>();
+        assert_named(&c, &c.curve.q);
+        assert_eq!(c.cofactor, BigInt::from(8));
+        let c = curves::ed448::<BigInt>();
+        assert_named(&c, &c.curve.q);
+        assert_eq!(c.cofactor, BigInt::from(4));
+    }
+
+    #[test]
+    fn test_matches_protocols() {
+        let ed = Ed25519::<BigInt>::new();
+        let c = curves::ed25519::<BigInt>();
+        assert_eq!(&c.generator, ed.base());
+        assert_eq!(&c.order, ed.order());
+        // Curve25519 and edwards25519 are birationally equivalent: y = (u − 1)/(u + 1).
+        let m = curves::curve25519::<BigInt>();
+        let field = c.curve.field();
+        let u = field.element(m.generator.x.clone());
+        let y = (u.clone() - field.one()) / (u + field.one());
+        assert_eq!(y.value(), c.generator.y);
+        let bip = Bip340::<BigInt>::new();
+        assert_eq!(&curves::secp256k1::<BigInt>().generator, bip.generator());
+    }
+
+    #[test]
+    fn test_check_rejects() {
+        let mut c = curves::p256::<BigInt>();
+        c.order -= 1;
+        assert!(matches!(c.check(), Err(EccError::InvalidParameters(_))));
+        let mut c = curves::ed25519::<BigInt>();
+        c.generator.x += 1;
+        assert_eq!(c.check(), Err(EccError::NotOnCurve));
+        c.generator = Point::identity();
+        assert_eq!(c.check(), Err(EccError::NotOnCurve));
+    }
+}
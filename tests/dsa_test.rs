@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves;
+    use KissECC::error::EccError;
+    use KissECC::dsa::DSA;
+    use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::weierstrass_ecc::WeierstrassECC;
+    use KissECC::utils::Utils;
+    use num_bigint::BigInt;
+    use sha2::{Digest, Sha256, Sha512};
+
+    /// NIST P-256 with its published generator and order.
+    fn p256() -> DSA<BigInt> {
+        let p256 = curves::p256();
+        DSA::with_order(p256.generator, p256.order, Box::new(p256.curve))
+    }
+
+    /// Key pair of RFC 6979, appendix A.2.5.
+    fn rfc6979_key(dsa: &DSA<BigInt>) -> (Scalar<BigInt>, Point<BigInt>) {
+        let d = Scalar::modulo(Utils::from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"), &dsa.n);
+        let pk = dsa.ec.mul(&d, &dsa.g);
+        (d, pk)
+    }
+
+    #[test]
+    fn test_gen_key_small_curve() {
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        let g = Point { x: 3, y: 6, z: 1 };
+        let dsa = DSA::new(g.clone(), Box::new(ecc));
+        assert_eq!(dsa.n, 11);
+        for _ in 0..20 {
+            let (d, pk) = dsa.gen_key();
+            assert!(*d.value() >= 1 && *d.value() < dsa.n);
+            assert!(dsa.ec.is_valid(&pk));
+            assert_eq!(dsa.ec.mul(&d, &g), pk);
+        }
+    }
+
+    #[test]
+    fn test_gen_key_p256() {
+        let dsa = p256();
+        let n = dsa.n.clone();
+        let (d, pk) = dsa.gen_key();
+        assert!(*d.value() > BigInt::from(0) && *d.value() < n);
+        assert!(dsa.ec.is_valid(&pk));
+        // n·pk = identity since pk lies in the subgroup generated by g.
+        let identity = dsa.ec.mul(&Scalar::from(0), &pk);
+        assert_eq!(dsa.ec.mul(&Scalar::new(n), &pk), identity);
+    }
+
+    #[test]
+    fn test_errors() {
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        let off_curve = Point { x: 5, y: 1, z: 1 };
+        assert_eq!(DSA::try_new(off_curve, Box::new(ecc)).err().map(|e| e.to_string()),
+                   Some("point is not on the curve".to_string()));
+
+        // (3, 6) has order 11, so neither 7 nor the multiple 22 is accepted.
+        let g = Point { x: 3, y: 6, z: 1 };
+        for n in [7, 22] {
+            let ecc = WeierstrassECC::new(2i64, 3, 17);
+            assert!(matches!(DSA::try_with_order(g.clone(), n, Box::new(ecc)), Err(EccError::InvalidParameters(_))));
+        }
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        assert_eq!(DSA::try_with_order(g, 11, Box::new(ecc)).map(|dsa| dsa.n), Ok(11));
+    }
+
+    #[test]
+    fn test_ecdsa_p256_vectors() {
+        let dsa = p256();
+        let (d, pk) = rfc6979_key(&dsa);
+        // (message, k, r, s) for ECDSA over P-256 with SHA-256, RFC 6979 A.2.5.
+        let vectors = [
+            (
+                "sample",
+                "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60",
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+                "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+            ),
+            (
+                "test",
+                "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0",
+                "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+                "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+            ),
+        ];
+        for (message, k, r, s) in vectors {
+            let digest = Sha256::digest(message.as_bytes());
+            let k = Scalar::modulo(Utils::from_hex(k), &dsa.n);
+            let signature = dsa.sign_digest(&digest, &d, &k).unwrap();
+            assert_eq!(signature, (Utils::from_hex(r), Utils::from_hex(s)));
+            assert!(dsa.verify(message.as_bytes(), &signature, &pk));
+            assert!(!dsa.verify(b"other message", &signature, &pk));
+        }
+    }
+
+    #[test]
+    fn test_ecdsa_digest_truncation() {
+        // SHA-512 digests are longer than n and keep only their leftmost 256 bits.
+        let dsa = p256();
+        let (d, pk) = rfc6979_key(&dsa);
+        let digest = Sha512::digest(b"sample");
+        let k = Scalar::modulo(Utils::from_hex("5fa81c63109badb88c1f367b47da606da28cad69aa22c4fe6ad7df73a7173aa5"), &dsa.n);
+        let signature = dsa.sign_digest(&digest, &d, &k).unwrap();
+        assert_eq!(signature, (
+            Utils::from_hex("8496a60b5e9b47c825488827e0495b0e3fa109ec4568fd3f8d1097678eb97f00"),
+            Utils::from_hex("2362ab1adbe2b8adf9cb9edab740ea6049c028114f2460f96554f61fae3302fe"),
+        ));
+        assert!(dsa.verify_digest(&digest, &signature, &pk));
+    }
+
+    #[test]
+    fn test_ecdsa_sign_verify() {
+        let dsa = p256();
+        let (d, pk) = dsa.gen_key();
+        let signature = dsa.sign(b"hello", &d).unwrap();
+        assert!(dsa.verify(b"hello", &signature, &pk));
+        assert!(!dsa.verify(b"hellp", &signature, &pk));
+        let (_, other_pk) = dsa.gen_key();
+        assert!(!dsa.verify(b"hello", &signature, &other_pk));
+
+        // r and s must lie in [1, n - 1].
+        let (r, s) = signature.clone();
+        assert!(!dsa.verify(b"hello", &(BigInt::from(0), s.clone()), &pk));
+        assert!(!dsa.verify(b"hello", &(r.clone(), dsa.n.clone()), &pk));
+        assert!(!dsa.verify(b"hello", &(r.clone() + &dsa.n, s.clone()), &pk));
+        // (r, -s) is also valid, so only the range check rejects (r, 2n - s).
+        assert!(dsa.verify(b"hello", &(r.clone(), &dsa.n - &s), &pk));
+        assert!(!dsa.verify(b"hello", &(r, BigInt::from(2) * &dsa.n - s), &pk));
+
+        let zero = Scalar::new(BigInt::from(0));
+        assert!(matches!(dsa.sign(b"hello", &zero), Err(EccError::InvalidParameters(_))));
+    }
+
+    #[test]
+    fn test_ecdsa_small_curve() {
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        let dsa = DSA::new(Point { x: 3, y: 6, z: 1 }, Box::new(ecc));
+        for _ in 0..20 {
+            let (d, pk) = dsa.gen_key();
+            let signature = dsa.sign(b"toy", &d).unwrap();
+            assert!(dsa.verify(b"toy", &signature, &pk));
+        }
+    }
+}
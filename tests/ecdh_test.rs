@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves::{self, NamedCurve};
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::ecdh::{self, Xdh};
+    use KissECC::edwards_curve::EdwardsCurve;
+    use KissECC::error::EccError;
+    use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::twisted_curve::TwistedCurve;
+    use KissECC::utils::Utils;
+    use KissECC::weierstrass_ecc::WeierstrassECC;
+    use num_bigint::BigInt;
+    use sha2::Sha256;
+
+    fn bytes(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// RFC 7748 §5.2: k = u = base point, then repeatedly k, u ← X(k, u), k.
+    fn iterate(xdh: &Xdh<BigInt>, base: u8, iterations: usize) -> Vec<u8> {
+        let mut k = vec![0u8; xdh.key_len()];
+        k[0] = base;
+        let mut u = k.clone();
+        for _ in 0..iterations {
+            let next = xdh.scalar_mult(&k, &u).unwrap();
+            u = k;
+            k = next;
+        }
+        k
+    }
+
+    #[test]
+    fn test_x25519_vectors() {
+        let xdh = Xdh::<BigInt>::x25519();
+        let vectors = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+        for (k, u, expected) in vectors {
+            assert_eq!(xdh.scalar_mult(&bytes(k), &bytes(u)).unwrap(), bytes(expected));
+        }
+    }
+
+    #[test]
+    fn test_x448_vectors() {
+        let xdh = Xdh::<BigInt>::x448();
+        let vectors = [
+            (
+                "3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3",
+                "06fce640fa3487bfda5f6cf2d5263f8aad88334cbd07437f020f08f9814dc031ddbdc38c19c6da2583fa5429db94ada18aa7a7fb4ef8a086",
+                "ce3e4ff95a60dc6697da1db1d85e6afbdf79b50a2412d7546d5f239fe14fbaadeb445fc66a01b0779d98223961111e21766282f73dd96b6f",
+            ),
+            (
+                "203d494428b8399352665ddca42f9de8fef600908e0d461cb021f8c538345dd77c3e4806e25f46d3315c44e0a5b4371282dd2c8d5be3095f",
+                "0fbcc2f993cd56d3305b0b7d9e55d4c1a8fb5dbb52f8e9a1e9b6201b165d015894e56c4d3570bee52fe205e28a78b91cdfbde71ce8d157db",
+                "884a02576239ff7a2f2f63b2db6a9ff37047ac13568e1e30fe63c4a7ad1b3ee3a5700df34321d62077e63633c575c1c954514e99da7c179d",
+            ),
+        ];
+        for (k, u, expected) in vectors {
+            assert_eq!(xdh.scalar_mult(&bytes(k), &bytes(u)).unwrap(), bytes(expected));
+        }
+    }
+
+    #[test]
+    fn test_x25519_iterated() {
+        let xdh = Xdh::<BigInt>::x25519();
+        assert_eq!(iterate(&xdh, 9, 1), bytes("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"));
+        assert_eq!(iterate(&xdh, 9, 1000), bytes("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"));
+    }
+
+    #[test]
+    fn test_x448_iterated() {
+        let xdh = Xdh::<BigInt>::x448();
+        assert_eq!(
+            iterate(&xdh, 5, 1),
+            bytes("3f482c8a9f19b01e6c46ee9711d9dc14fd4bf67af30765c2ae2b846a4d23a8cd0db897086239492caf350b51f833868b9bc2b3bca9cf4113")
+        );
+        assert_eq!(
+            iterate(&xdh, 5, 1000),
+            bytes("aa3b4749d55b9daf1e5b00288826c467274ce3ebbdd5c17b975e09d4af6c67cf10d087202db88286e2b79fceea3ec353ef54faa26e219f38")
+        );
+    }
+
+    #[test]
+    fn test_diffie_hellman() {
+        // RFC 7748 §6.1 and §6.2: (Alice's secret, Alice's public, Bob's secret, Bob's public, shared).
+        let cases = [
+            (
+                Xdh::<BigInt>::x25519(),
+                "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+                "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a",
+                "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb",
+                "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f",
+                "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742",
+            ),
+            (
+                Xdh::<BigInt>::x448(),
+                "9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b",
+                "9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0",
+                "1c306a7ac2a0e2e0990b294470cba339e6453772b075811d8fad0d1d6927c120bb5ee8972b0d3e21374c9c921b09d1b0366f10b65173992d",
+                "3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609",
+                "07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56fd2464c335543936521c24403085d59a449a5037514a879d",
+            ),
+        ];
+        for (xdh, a, a_pub, b, b_pub, shared) in cases {
+            let (a, b) = (bytes(a), bytes(b));
+            assert_eq!(xdh.public_key(&a).unwrap(), bytes(a_pub));
+            assert_eq!(xdh.public_key(&b).unwrap(), bytes(b_pub));
+            assert_eq!(xdh.shared_secret(&a, &bytes(b_pub)).unwrap(), bytes(shared));
+            assert_eq!(xdh.shared_secret(&b, &bytes(a_pub)).unwrap(), bytes(shared));
+        }
+    }
+
+    #[test]
+    fn test_rejections() {
+        let xdh = Xdh::<BigInt>::x25519();
+        let secret = bytes("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        // u = 0 and u = 1 have small order, so the shared secret is all zeros.
+        let mut small_order = vec![0u8; 32];
+        assert_eq!(xdh.shared_secret(&secret, &small_order), Err(EccError::NotOnCurve));
+        small_order[0] = 1;
+        assert_eq!(xdh.shared_secret(&secret, &small_order), Err(EccError::NotOnCurve));
+        assert!(matches!(xdh.shared_secret(&secret[..31], &small_order), Err(EccError::Encoding(_))));
+        assert!(matches!(xdh.public_key(&[0u8; 56]), Err(EccError::Encoding(_))));
+    }
+
+    #[test]
+    fn test_shared_secret_p256() {
+        let NamedCurve { curve, order: n, .. } = curves::p256::<BigInt>();
+        let private = Scalar::modulo(Utils::from_hex("c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433"), &n);
+        let peer = Point {
+            x: Utils::from_hex("ead218590119e8876b29146ff89ca61770c4edbbf97d38ce385ed281d8a6b230"),
+            y: Utils::from_hex("28af61281fd35e2fa7002523acc85a429cb06ee6648325389f59edfce1405141"),
+            z: BigInt::from(1),
+        };
+        let z = ecdh::shared_secret(&curve, &private, &peer).unwrap();
+        assert_eq!(z, Utils::from_hex("dc1c6902b068c697c133fe5e61bf4f6a5f84c011fe75a084b49527282e4a8ef3"));
+
+        // The x-coordinate is encoded as a 32-byte field element before key derivation.
+        let z = Utils::to_bytes_be(&z, 32);
+        let key = ecdh::hkdf::<Sha256>(&z, b"", b"", 32).unwrap();
+        assert_eq!(key.len(), 32);
+        assert_ne!(key, ecdh::x963_kdf::<Sha256>(&z, b"", 32).unwrap());
+    }
+
+    #[test]
+    fn test_shared_secret_small_curves() {
+        // y² = x³ + 2x + 3 over F_17 has 22 points; (3, 6) has order 11 and (16, 0) order 2.
+        let weierstrass = WeierstrassECC::new(2i64, 3, 17);
+        let g = Point { x: 3, y: 6, z: 1 };
+        let outside = Point { x: 16, y: 0, z: 1 };
+        agree(&weierstrass, &g, 11, &outside);
+
+        // 2x² + y² = 1 + 3x²y² over F_17 has 24 points; (1, 3) has order 3 and (6, 2) order 8.
+        let g = Point { x: 1, y: 3, z: 1 };
+        let outside = Point { x: 6, y: 2, z: 1 };
+        agree(&EdwardsCurve::new(2i64, 3, 17), &g, 3, &outside);
+        agree(&TwistedCurve::new(2i64, 3, 17, 3), &g, 3, &outside);
+    }
+
+    /// Checks that every pair of keys in the subgroup of order n generated by
+    /// g agrees on the secret, and that `outside` is rejected.
+    fn agree(curve: &dyn EllipticCurve<i64>, g: &Point<i64>, n: i64, outside: &Point<i64>) {
+        for a in 1..n {
+            for b in 1..n {
+                let (a, b) = (Scalar::modulo(a, &n), Scalar::modulo(b, &n));
+                let (a_pub, b_pub) = (curve.mul(&a, g), curve.mul(&b, g));
+                assert_eq!(
+                    ecdh::shared_secret(curve, &a, &b_pub),
+                    ecdh::shared_secret(curve, &b, &a_pub),
+                    "{}", curve.display()
+                );
+            }
+            assert_eq!(ecdh::shared_secret(curve, &Scalar::modulo(a, &n), outside), Err(EccError::NotOnCurve));
+        }
+    }
+
+    #[test]
+    fn test_shared_secret_rejections() {
+        let curve = WeierstrassECC::new(2i64, 3, 17);
+        let private = Scalar::modulo(5, &11);
+        let identity = Point::identity();
+        let off_curve = Point { x: 5, y: 1, z: 1 };
+        assert_eq!(ecdh::shared_secret(&curve, &private, &identity), Err(EccError::NotOnCurve));
+        assert_eq!(ecdh::shared_secret(&curve, &private, &off_curve), Err(EccError::NotOnCurve));
+
+        let peer = Point { x: 3, y: 6, z: 1 };
+        assert!(ecdh::shared_secret(&curve, &private, &peer).is_ok());
+        let unreduced = Scalar::new(5);
+        assert!(matches!(ecdh::shared_secret(&curve, &unreduced, &peer), Err(EccError::InvalidParameters(_))));
+        let zero = Scalar::modulo(0, &11);
+        assert!(matches!(ecdh::shared_secret(&curve, &zero, &peer), Err(EccError::InvalidParameters(_))));
+
+        let edwards = EdwardsCurve::new(2i64, 3, 17);
+        let private = Scalar::modulo(2, &3);
+        assert_eq!(ecdh::shared_secret(&edwards, &private, &edwards.zero), Err(EccError::NotOnCurve));
+        // (0, 16) has order 2 and (2, 8) order 24, so neither is in the subgroup of order 3.
+        for peer in [Point { x: 0, y: 16, z: 1 }, Point { x: 2, y: 8, z: 1 }] {
+            assert_eq!(ecdh::shared_secret(&edwards, &private, &peer), Err(EccError::NotOnCurve));
+        }
+    }
+
+    #[test]
+    fn test_hkdf() {
+        // RFC 5869 A.1 and A.3.
+        let ikm = [0x0bu8; 22];
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+        assert_eq!(
+            ecdh::hkdf::<Sha256>(&ikm, &salt, &info, 42).unwrap(),
+            bytes("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")
+        );
+        assert_eq!(
+            ecdh::hkdf::<Sha256>(&ikm, b"", b"", 42).unwrap(),
+            bytes("8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8")
+        );
+        assert!(matches!(ecdh::hkdf::<Sha256>(&ikm, b"", b"", 255 * 32 + 1), Err(EccError::InvalidParameters(_))));
+    }
+
+    #[test]
+    fn test_x963_kdf() {
+        // NIST CAVS ANSI X9.63 KDF vectors with SHA-256.
+        assert_eq!(
+            ecdh::x963_kdf::<Sha256>(&bytes("96c05619d56c328ab95fe84b18264b08725b85e33fd34f08"), b"", 16).unwrap(),
+            bytes("443024c3dae66b95e6f5670601558f71")
+        );
+        assert_eq!(
+            ecdh::x963_kdf::<Sha256>(
+                &bytes("22518b10e70f2a3f243810ae3254139efbee04aa57c7af7d"),
+                &bytes("75eef81aa3041e33b80971203d2c0c52"),
+                128
+            ).unwrap(),
+            bytes("c498af77161cc59f2962b9a713e2b215152d139766ce34a776df11866a69bf2e52a13d9c7c6fc878c50c5ea0bc7b00e0da2447cfd874f6cf92f30d0097111485500c90c3af8b487872d04685d14c8d1dc8d7fa08beb0ce0ababc11f0bd496269142d43525a78e5bc79a17f59676a5706dc54d54d4d1f0bd7e386128ec26afc21")
+        );
+    }
+}
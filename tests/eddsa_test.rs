@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::eddsa::Ed25519;
+    use KissECC::error::EccError;
+    use KissECC::scalar::Scalar;
+    use num_bigint::BigInt;
+
+    fn bytes<const N: usize>(s: &str) -> [u8; N] {
+        let v: Vec<u8> = (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect();
+        v.try_into().unwrap()
+    }
+
+    fn hex_vec(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_base_point() {
+        let ed = Ed25519::<BigInt>::new();
+        let curve = ed.curve();
+        assert!(curve.is_valid(ed.base()));
+        assert_eq!(
+            ed.base().x.to_string(),
+            "15112221349535400772501151409588531511454012693041857206046113283949847762202"
+        );
+        assert_eq!(curve.mul(&Scalar::new(ed.order().clone()), ed.base()), curve.zero);
+    }
+
+    #[test]
+    fn test_ed25519_vectors() {
+        // RFC 8032 §7.1: TEST 1, 2, 3 and SHA(abc).
+        let vectors = [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+            (
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+                "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+                "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+            ),
+        ];
+        let ed = Ed25519::<BigInt>::new();
+        for (secret, public, message, signature) in vectors {
+            let (secret, public, message) = (bytes(secret), bytes(public), hex_vec(message));
+            let signature: [u8; 64] = bytes(signature);
+            assert_eq!(ed.public_key(&secret), public);
+            assert_eq!(ed.sign(&secret, &message), signature);
+            assert!(ed.verify(&public, &message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_ed25519ctx_vectors() {
+        // RFC 8032 §7.2: (secret, public, message, context, signature).
+        let vectors = [
+            (
+                "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+                "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+                "f726936d19c800494e3fdaff20b276a8",
+                "666f6f",
+                "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+            ),
+            (
+                "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+                "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+                "f726936d19c800494e3fdaff20b276a8",
+                "626172",
+                "fc60d5872fc46b3aa69f8b5b4351d5808f92bcc044606db097abab6dbcb1aee3216c48e8b3b66431b5b186d1d28f8ee15a5ca2df6668346291c2043d4eb3e90d",
+            ),
+            (
+                "0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6",
+                "dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292",
+                "508e9e6882b979fea900f62adceaca35",
+                "666f6f",
+                "8b70c1cc8310e1de20ac53ce28ae6e7207f33c3295e03bb5c0732a1d20dc64908922a8b052cf99b7c4fe107a5abb5b2c4085ae75890d02df26269d8945f84b0b",
+            ),
+            (
+                "ab9c2853ce297ddab85c993b3ae14bcad39b2c682beabc27d6d4eb20711d6560",
+                "0f1d1274943b91415889152e893d80e93275a1fc0b65fd71b4b0dda10ad7d772",
+                "f726936d19c800494e3fdaff20b276a8",
+                "666f6f",
+                "21655b5f1aa965996b3f97b3c849eafba922a0a62992f73b3d1b73106a84ad85e9b86a7b6005ea868337ff2d20a7f5fbd4cd10b0be49a68da2b2e0dc0ad8960f",
+            ),
+        ];
+        let ed = Ed25519::<BigInt>::new();
+        for (secret, public, message, context, signature) in vectors {
+            let (secret, public) = (bytes(secret), bytes(public));
+            let (message, context) = (hex_vec(message), hex_vec(context));
+            let signature: [u8; 64] = bytes(signature);
+            assert_eq!(ed.public_key(&secret), public);
+            assert_eq!(ed.sign_ctx(&secret, &message, &context), Ok(signature));
+            assert!(ed.verify_ctx(&public, &message, &signature, &context));
+            // The context is bound to the signature.
+            assert!(!ed.verify_ctx(&public, &message, &signature, b"baz"));
+            assert!(!ed.verify(&public, &message, &signature));
+        }
+    }
+
+    #[test]
+    fn test_ed25519ph_vector() {
+        // RFC 8032 §7.3, message "abc" with an empty context.
+        let secret = bytes("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42");
+        let public = bytes("ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf");
+        let signature: [u8; 64] = bytes("98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406");
+        let ed = Ed25519::<BigInt>::new();
+        assert_eq!(ed.sign_ph(&secret, b"abc", b""), Ok(signature));
+        assert!(ed.verify_ph(&public, b"abc", &signature, b""));
+        assert!(!ed.verify_ph(&public, b"abd", &signature, b""));
+        assert!(!ed.verify(&public, b"abc", &signature));
+    }
+
+    #[test]
+    fn test_rejections() {
+        let ed = Ed25519::<BigInt>::new();
+        let secret = bytes("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60");
+        let public = ed.public_key(&secret);
+        let signature = ed.sign(&secret, b"");
+
+        // S + L verifies algebraically but is not a canonical encoding.
+        let l = ed.order().clone();
+        let s = BigInt::from_bytes_le(num_bigint::Sign::Plus, &signature[32..]) + l;
+        let mut malleated = signature;
+        malleated[32..].copy_from_slice(&s.to_bytes_le().1);
+        assert!(!ed.verify(&public, b"", &malleated));
+
+        // y = q is non-canonical; y = 1 with the sign bit set encodes x = 0 with a set sign.
+        let mut non_canonical = [0xffu8; 32];
+        non_canonical[0] = 0xed;
+        non_canonical[31] = 0x7f;
+        assert!(!ed.verify(&non_canonical, b"", &signature));
+        let mut negative_zero = [0u8; 32];
+        negative_zero[0] = 1;
+        negative_zero[31] = 0x80;
+        assert!(!ed.verify(&negative_zero, b"", &signature));
+
+        let mut tampered = signature;
+        tampered[0] ^= 1;
+        assert!(!ed.verify(&public, b"", &tampered));
+
+        assert!(matches!(ed.sign_ctx(&secret, b"", b""), Err(EccError::InvalidParameters(_))));
+        assert!(matches!(ed.sign_ph(&secret, b"", &[0u8; 256]), Err(EccError::InvalidParameters(_))));
+    }
+}
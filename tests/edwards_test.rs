@@ -1,24 +1,28 @@
 #[cfg(test)]
 mod tests {
+    use KissECC::curves;
+    use KissECC::error::EccError;
     use KissECC::ecc::{EllipticCurve};
     use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
     use KissECC::edwards_curve::EdwardsCurve;
+    use num_bigint::BigInt;
 
     #[test]
     fn test_is_valid() {
         let ecc = EdwardsCurve::new(2, 3, 17);
-        let invalid = Point { x: 5, y: 1, z: 0 };
-        assert_eq!(ecc.is_valid(&invalid), false);
-        let valid = Point { x: 1, y: 3, z: 0 };
-        assert_eq!(ecc.is_valid(&valid), true);
+        let invalid = Point { x: 5, y: 1, z: 1 };
+        assert!(!ecc.is_valid(&invalid));
+        let valid = Point { x: 1, y: 3, z: 1 };
+        assert!(ecc.is_valid(&valid));
     }
 
     #[test]
     fn test_add() {
         let ecc = EdwardsCurve::new(2, 3, 17);
         // The identity for Edwards is (0, 1, 0)
-        let identity = Point { x: 0, y: 1, z: 0 };
-        let p = Point { x: 1, y: 3, z: 0 };
+        let identity = Point::identity();
+        let p = Point { x: 1, y: 3, z: 1 };
         let sum = ecc.add(&identity, &p);
         assert_eq!(sum, p);
     }
@@ -26,13 +30,135 @@ mod tests {
     #[test]
     fn test_mul() {
         let ecc = EdwardsCurve::new(2, 3, 17);
-        let identity = Point { x: 0, y: 1, z: 0 };
-        let p = Point { x: 1, y: 3, z: 0 };
+        let identity = Point::identity();
+        let p = Point { x: 1, y: 3, z: 1 };
 
-        let r0 = ecc.mul(0, &p);
+        let r0 = ecc.mul(&Scalar::from(0), &p);
         assert_eq!(r0, identity);
 
-        let r1 = ecc.mul(1, &p);
+        let r1 = ecc.mul(&Scalar::from(1), &p);
         assert_eq!(r1, p);
     }
+
+    #[test]
+    fn test_add_stays_on_curve() {
+        // a = 2 exercises the −a*x₁*x₂ term of the addition law.
+        let ecc = EdwardsCurve::new(2, 3, 17);
+        let p = Point { x: 1, y: 3, z: 1 };
+        let mut acc = p.clone();
+        for _ in 0..10 {
+            acc = ecc.add(&acc, &p);
+            assert!(ecc.is_valid(&acc));
+        }
+        assert_eq!(ecc.mul(&Scalar::from(11), &p), acc);
+    }
+
+    /// Every affine point of the curve, found by trying all y with `at`.
+    fn all_points(ecc: &EdwardsCurve<i64>) -> Vec<Point<i64>> {
+        let mut points = Vec::new();
+        for y in 0..17 {
+            if let Ok((p1, p2)) = ecc.at(y) {
+                points.push(p1.clone());
+                if p2 != p1 {
+                    points.push(p2);
+                }
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_extended_matches_affine() {
+        let ecc = EdwardsCurve::new(2i64, 3, 17);
+        let points = all_points(&ecc);
+        assert!(points.len() > 4);
+        for p in &points {
+            let ep = ecc.to_extended(p);
+            for q in &points {
+                let eq = ecc.to_extended(q);
+                assert_eq!(ecc.to_affine(&ecc.extended_add(&ep, &eq)), Ok(ecc.add(p, q)));
+            }
+            assert_eq!(ecc.to_affine(&ecc.extended_double(&ep)), Ok(ecc.add(p, p)));
+            assert_eq!(ecc.double(p), ecc.add(p, p));
+
+            let mut expected = ecc.zero.clone();
+            for n in 0..30 {
+                assert_eq!(ecc.mul(&Scalar::from(n), p), expected);
+                expected = ecc.add(&expected, p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(EdwardsCurve::try_new(0i64, 3, 17), Err(EccError::InvalidParameters(_))));
+        assert!(matches!(EdwardsCurve::try_new(3i64, 3, 17), Err(EccError::InvalidParameters(_))));
+        // Incomplete addition laws: d = 4 is a square, a = 3 is not.
+        assert!(matches!(EdwardsCurve::try_new(2i64, 4, 17), Err(EccError::InvalidParameters(_))));
+        assert!(matches!(EdwardsCurve::try_new(3i64, 5, 17), Err(EccError::InvalidParameters(_))));
+        let ecc = EdwardsCurve::try_new(2i64, 3, 17).unwrap();
+        let off_curve = Point { x: 1, y: 1, z: 1 };
+        assert_eq!(ecc.checked_add(&off_curve, &ecc.zero), Err(EccError::NotOnCurve));
+        assert_eq!(ecc.order(&off_curve), Err(EccError::NotOnCurve));
+        let missing = (0..17).find(|&y| ecc.at(y).is_err()).unwrap();
+        assert_eq!(ecc.xrecover(missing), Err(EccError::NoSquareRoot));
+    }
+
+    #[test]
+    fn test_identity() {
+        let ecc = EdwardsCurve::new(2i64, 3, 17);
+        let p = Point::new(1, 3);
+        // The neutral element is also the affine point (0, 1), which is accepted as input.
+        let affine_zero = Point::new(0, 1);
+        assert!(ecc.is_identity(&affine_zero));
+        assert!(ecc.is_identity(&ecc.zero));
+        assert!(!ecc.is_identity(&p));
+        assert_eq!(ecc.add(&p, &affine_zero), p);
+        // Results are always returned in canonical form.
+        assert_eq!(ecc.add(&p, &ecc.neg(&p)), Point::identity());
+        assert_eq!(ecc.mul(&Scalar::from(3), &p), Point::identity());
+        assert_eq!(ecc.neg(&affine_zero), Point::identity());
+        assert_eq!(ecc.order(&affine_zero), Ok(1));
+        // (0, −1) has order two and is not the identity.
+        let t = Point::new(0, 16);
+        assert!(!ecc.is_identity(&t));
+        assert_eq!(ecc.double(&t), Point::identity());
+    }
+
+    #[test]
+    fn test_encoding_ed448() {
+        // Ed448-Goldilocks: x² + y² = 1 − 39081x²y² over 2^448 − 2^224 − 1 (RFC 8032 §5.2).
+        let ecc = curves::ed448::<BigInt>().curve;
+        let y = BigInt::parse_bytes(b"298819210078481492676017930443930673437544040154080242095928241372331506189835876003536878655418784733982303233503462500531545062832660", 10).unwrap();
+        let b = ecc.at(y).unwrap().0;
+        let encoded: Vec<u8> = (0..114)
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&"14fa30f25b790898adc8d74e2c13bdfdc4397ce61cffd33ad7c2a0051e9c78874098a36c7373ea4b62c7c9563720768824bcb66e71463f6900"[i..i + 2], 16).unwrap())
+            .collect();
+        assert_eq!(ecc.encoded_len(), 57);
+        assert_eq!(ecc.encode_point(&b), encoded);
+        assert_eq!(ecc.decode_point(&encoded), Ok(b.clone()));
+        let p = ecc.mul(&Scalar::from(1234), &b);
+        assert_eq!(ecc.decode_point(&ecc.encode_point(&p)), Ok(p));
+        // The 57th byte only holds the sign bit.
+        let mut high_bits = encoded;
+        high_bits[56] |= 0x01;
+        assert!(matches!(ecc.decode_point(&high_bits), Err(EccError::Encoding(_))));
+    }
+
+    #[test]
+    fn test_encoding_small_curve() {
+        let ecc = EdwardsCurve::new(2i64, 3, 17);
+        assert_eq!(ecc.encoded_len(), 1);
+        let mut decoded = 0;
+        for byte in 0..=255u8 {
+            if let Ok(p) = ecc.decode_point(&[byte]) {
+                assert!(ecc.is_valid(&p));
+                assert_eq!(ecc.encode_point(&p), vec![byte]);
+                decoded += 1;
+            }
+        }
+        // Every one of the 24 points has exactly one encoding.
+        assert_eq!(decoded, 24);
+    }
 }
@@ -0,0 +1,184 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves;
+    use KissECC::field::{PrimeField, Reduction};
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_arithmetic_stays_reduced() {
+        let f = PrimeField::new(17i64);
+        let a = f.element(5);
+        let b = f.element(12);
+        assert_eq!((&a + &b).value(), 0);
+        assert_eq!((&a - &b).value(), 10);
+        assert_eq!((&a * &b).value(), 9);
+        assert_eq!((-&a).value(), 12);
+        assert_eq!((-f.zero()).value(), 0);
+        // Negative and oversized inputs are reduced on creation.
+        assert_eq!(f.element(-3).value(), 14);
+        assert_eq!(f.element(40).value(), 6);
+    }
+
+    #[test]
+    fn test_inverse_and_div() {
+        let f = PrimeField::new(17i64);
+        for v in 1..17 {
+            let a = f.element(v);
+            assert!((&a * a.inverse().unwrap()).is_one());
+            assert_eq!(&(f.one() / &a) * &a, f.one());
+        }
+        assert!(f.zero().inverse().is_err());
+    }
+
+    #[test]
+    fn test_pow_and_legendre() {
+        let f = PrimeField::new(17i64);
+        assert_eq!(f.element(3).pow(&4).value(), 13);
+        assert_eq!(f.element(3).pow(&16).value(), 1);
+        assert_eq!(f.zero().legendre(), 0);
+        // The squares modulo 17 are {1, 2, 4, 8, 9, 13, 15, 16}.
+        assert_eq!(f.element(2).legendre(), 1);
+        assert_eq!(f.element(3).legendre(), -1);
+    }
+
+    #[test]
+    fn test_sqrt_all_residue_classes() {
+        // 23 ≡ 3 (mod 4), 13 ≡ 5 (mod 8), 17 ≡ 1 (mod 8) and 41 ≡ 1 (mod 8).
+        for q in [23i64, 13, 17, 41] {
+            let f = PrimeField::new(q);
+            for v in 0..q {
+                let a = f.element(v);
+                match a.sqrt() {
+                    Some(r) => assert_eq!(r.square(), a),
+                    None => assert_eq!(a.legendre(), -1),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bigint_field() {
+        let q = (BigInt::from(1) << 255usize) - BigInt::from(19);
+        let f = PrimeField::new(q.clone());
+        let minus_one = f.element(BigInt::from(-1));
+        assert_eq!(minus_one.value(), q.clone() - BigInt::from(1));
+        let i = minus_one.sqrt().unwrap();
+        assert_eq!(i.square(), minus_one);
+        let x = f.element(BigInt::from(123456789));
+        assert!((&x / &x).is_one());
+    }
+
+    #[test]
+    fn test_reductions_agree_small() {
+        for q in [17i64, 23, 13, 41, 65537] {
+            let generic = PrimeField::new(q);
+            for reduction in [Reduction::Montgomery, Reduction::Barrett] {
+                let f = PrimeField::with_reduction(q, reduction);
+                assert_eq!(f.reduction(), reduction);
+                assert!(f.one().is_one());
+                for a in (0..q).step_by(((q / 50) as usize).max(1)) {
+                    for b in (0..q).step_by(((q / 50) as usize).max(1)) {
+                        let expected = generic.element(a) * generic.element(b);
+                        let got = f.element(a) * f.element(b);
+                        assert_eq!(got.value(), expected.value());
+                    }
+                    if a != 0 {
+                        assert!((f.element(a) * f.element(a).inverse().unwrap()).is_one());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reductions_agree_256_bit() {
+        let q = curves::p256::<BigInt>().curve.q;
+        let generic = PrimeField::new(q.clone());
+        let mont = PrimeField::with_reduction(q.clone(), Reduction::Montgomery);
+        let barrett = PrimeField::with_reduction(q.clone(), Reduction::Barrett);
+        let mut x = BigInt::from(0x1234_5678_9abc_def0u64);
+        for _ in 0..200 {
+            // Walk through large values by repeated squaring plus a constant.
+            x = (x.clone() * x.clone() + BigInt::from(7)) % q.clone();
+            let y = q.clone() - x.clone() - BigInt::from(1);
+            let expected = (generic.element(x.clone()) * generic.element(y.clone())).value();
+            assert_eq!((mont.element(x.clone()) * mont.element(y.clone())).value(), expected);
+            assert_eq!((barrett.element(x.clone()) * barrett.element(y.clone())).value(), expected);
+        }
+        let minus_three = mont.element(BigInt::from(-3));
+        assert_eq!(minus_three.value(), q.clone() - BigInt::from(3));
+        match minus_three.sqrt() {
+            Some(r) => assert_eq!(r.square(), minus_three),
+            None => assert_eq!(generic.element(BigInt::from(-3)).legendre(), -1),
+        }
+    }
+
+    fn pow2(k: usize) -> BigInt {
+        BigInt::from(1) << k
+    }
+
+    #[test]
+    fn test_detect_special_forms() {
+        let p25519 = pow2(255) - BigInt::from(19);
+        let secp256k1 = pow2(256) - pow2(32) - BigInt::from(977);
+        let p521 = pow2(521) - BigInt::from(1);
+        let p192 = pow2(192) - pow2(64) - BigInt::from(1);
+        let p224 = pow2(224) - pow2(96) + BigInt::from(1);
+        let p256 = pow2(256) - pow2(224) + pow2(192) + pow2(96) - BigInt::from(1);
+        let p384 = pow2(384) - pow2(128) - pow2(96) + pow2(32) - BigInt::from(1);
+        let p448 = pow2(448) - pow2(224) - BigInt::from(1);
+        let brainpool = curves::brainpool_p256r1::<BigInt>().curve.q;
+
+        for q in [&p25519, &secp256k1, &p521] {
+            assert_eq!(Reduction::detect(q), Reduction::PseudoMersenne);
+        }
+        for q in [&p192, &p224, &p256, &p384, &p448] {
+            assert_eq!(Reduction::detect(q), Reduction::Solinas);
+        }
+        assert_eq!(Reduction::detect(&brainpool), Reduction::Generic);
+        assert_eq!(Reduction::detect(&17i64), Reduction::Generic);
+    }
+
+    #[test]
+    fn test_special_forms_agree_with_generic() {
+        let cases = [
+            (pow2(255) - BigInt::from(19), Reduction::PseudoMersenne),
+            (pow2(256) - pow2(32) - BigInt::from(977), Reduction::PseudoMersenne),
+            (pow2(521) - BigInt::from(1), Reduction::PseudoMersenne),
+            (pow2(224) - pow2(96) + BigInt::from(1), Reduction::Solinas),
+            (pow2(256) - pow2(224) + pow2(192) + pow2(96) - BigInt::from(1), Reduction::Solinas),
+            (pow2(384) - pow2(128) - pow2(96) + pow2(32) - BigInt::from(1), Reduction::Solinas),
+            (pow2(448) - pow2(224) - BigInt::from(1), Reduction::Solinas),
+        ];
+        for (q, reduction) in cases {
+            let generic = PrimeField::new(q.clone());
+            let special = PrimeField::with_reduction(q.clone(), reduction);
+            let mut x = q.clone() - BigInt::from(2);
+            for _ in 0..100 {
+                let y = (x.clone() * BigInt::from(0x9e37_79b9u32) + BigInt::from(1)) % q.clone();
+                let expected = (generic.element(x.clone()) * generic.element(y.clone())).value();
+                assert_eq!((special.element(x.clone()) * special.element(y.clone())).value(), expected);
+                x = expected;
+            }
+            // The largest possible product, (q−1)², is reduced correctly too.
+            let m = special.element(q.clone() - BigInt::from(1));
+            assert!(m.square().is_one());
+        }
+    }
+
+    #[test]
+    fn test_pseudo_mersenne_small_modulus() {
+        // 65521 = 2^16 − 15 is the largest 16-bit prime.
+        let q = 65521i64;
+        let generic = PrimeField::new(q);
+        let special = PrimeField::with_reduction(q, Reduction::PseudoMersenne);
+        for a in (0..q).step_by(997) {
+            for b in (0..q).step_by(1009) {
+                assert_eq!(
+                    (special.element(a) * special.element(b)).value(),
+                    (generic.element(a) * generic.element(b)).value()
+                );
+            }
+        }
+    }
+}
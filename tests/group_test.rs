@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::edwards_curve::EdwardsCurve;
+    use KissECC::error::EccError;
+    use KissECC::group::{group_structure, GroupStructure};
+    use KissECC::montgomery_curve::MontgomeryCurve;
+    use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::twisted_curve::TwistedCurve;
+    use KissECC::weierstrass_ecc::WeierstrassECC;
+
+    /// Checks that the points are valid and distinct, with the identity first.
+    fn check_points(ecc: &dyn EllipticCurve<i64>, points: &[Point<i64>]) {
+        assert!(points[0].is_identity());
+        for (i, p) in points.iter().enumerate() {
+            assert!(ecc.is_valid(p), "{:?}", p);
+            assert!(!points[..i].contains(p), "{:?} twice", p);
+        }
+    }
+
+    /// Checks that i*g1 + j*g2 runs through every point exactly once.
+    fn check_structure(ecc: &dyn EllipticCurve<i64>, points: &[Point<i64>], s: &GroupStructure<i64>) {
+        assert_eq!(s.order(), points.len() as i64);
+        assert_eq!(ecc.order(&s.g1), Ok(s.n1));
+        assert_eq!(ecc.order(&s.g2), Ok(s.n2));
+        let mut seen: Vec<Point<i64>> = Vec::new();
+        for i in 0..s.n1 {
+            for j in 0..s.n2 {
+                let p = ecc.add(&ecc.mul(&Scalar::new(i), &s.g1), &ecc.mul(&Scalar::new(j), &s.g2));
+                let p = if ecc.is_identity(&p) { Point::identity() } else { p };
+                assert!(points.contains(&p), "{:?}", p);
+                assert!(!seen.contains(&p), "{:?} twice", p);
+                seen.push(p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weierstrass_points() {
+        for (a, b, q) in [(2i64, 3, 17), (1, 8, 97), (0, 7, 101), (1, 0, 103)] {
+            let ecc = WeierstrassECC::new(a, b, q);
+            let points: Vec<Point<i64>> = ecc.points().collect();
+            check_points(&ecc, &points);
+            assert_eq!(Ok(points.len() as i64), ecc.group_order());
+        }
+    }
+
+    #[test]
+    fn test_weierstrass_structure() {
+        let cases = [
+            ((2i64, 3, 17), (22, 1)),
+            ((1, 8, 97), (116, 1)),
+            ((-1, 0, 13), (4, 2)),
+            ((0, 3, 13), (3, 3)),
+            ((1, 0, 17), (4, 4)),
+            ((0, 1, 31), (6, 6)),
+            ((0, 2, 73), (9, 9)),
+        ];
+        for ((a, b, q), (n1, n2)) in cases {
+            let ecc = WeierstrassECC::new(a, b, q);
+            let points: Vec<Point<i64>> = ecc.points().collect();
+            let s = group_structure(&ecc, &points).unwrap();
+            assert_eq!((s.n1, s.n2), (n1, n2), "{}", ecc.display());
+            assert_eq!(s.is_cyclic(), n2 == 1);
+            check_structure(&ecc, &points, &s);
+        }
+    }
+
+    #[test]
+    fn test_edwards_models() {
+        let ecc = EdwardsCurve::new(2i64, 3, 17);
+        let points: Vec<Point<i64>> = ecc.points().collect();
+        check_points(&ecc, &points);
+        assert_eq!(points.len(), 24);
+        let s = group_structure(&ecc, &points).unwrap();
+        check_structure(&ecc, &points, &s);
+
+        let ecc = EdwardsCurve::new(1i64, 2, 101);
+        let points: Vec<Point<i64>> = ecc.points().collect();
+        check_points(&ecc, &points);
+        check_structure(&ecc, &points, &group_structure(&ecc, &points).unwrap());
+
+        let ecc = TwistedCurve::new(2i64, 3, 17, 19);
+        let points: Vec<Point<i64>> = ecc.points().collect();
+        check_points(&ecc, &points);
+        check_structure(&ecc, &points, &group_structure(&ecc, &points).unwrap());
+    }
+
+    #[test]
+    fn test_montgomery() {
+        for (a, b, q) in [(3i64, 1, 17), (5, 3, 101)] {
+            let ecc = MontgomeryCurve::new(a, b, q, 0);
+            let points: Vec<Point<i64>> = ecc.points().collect();
+            check_points(&ecc, &points);
+            // (0, 0) has order two, so the group has even order.
+            assert!(points.contains(&Point::new(0, 0)));
+            assert_eq!(points.len() % 2, 0);
+            let s = group_structure(&ecc, &points).unwrap();
+            check_structure(&ecc, &points, &s);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let ecc = WeierstrassECC::new(1i64, 0, 17);
+        let mut points: Vec<Point<i64>> = ecc.points().collect();
+        points.pop();
+        assert!(matches!(group_structure(&ecc, &points), Err(EccError::InvalidParameters(_))));
+        points.push(Point::new(1, 1));
+        assert_eq!(group_structure(&ecc, &points).err(), Some(EccError::NotOnCurve));
+    }
+}
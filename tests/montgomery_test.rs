@@ -1,22 +1,44 @@
 #[cfg(test)]
 mod tests {
+    use KissECC::error::EccError;
     use KissECC::ecc::{EllipticCurve};
     use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
     use KissECC::montgomery_curve::MontgomeryCurve;
+    use num_bigint::BigInt;
+
+    fn dec(s: &str) -> BigInt {
+        BigInt::parse_bytes(s.as_bytes(), 10).unwrap()
+    }
+
+    /// Every finite point of 1*y² = x³ + 3x² + x mod 17, by brute force.
+    fn small_curve() -> (MontgomeryCurve<i64>, Vec<Point<i64>>) {
+        let ecc = MontgomeryCurve::new(3i64, 1, 17, 0);
+        let mut points = Vec::new();
+        for x in 0..17 {
+            for y in 0..17 {
+                let p = Point { x, y, z: 1 };
+                if ecc.is_valid(&p) {
+                    points.push(p);
+                }
+            }
+        }
+        (ecc, points)
+    }
 
     #[test]
     fn test_is_valid() {
         // Example parameters (A, B, q, order) for demonstration.
         let ecc = MontgomeryCurve::new(2, 3, 17, 19);
-        let identity = Point { x: 0, y: 1, z: 0 };
-        assert_eq!(ecc.is_valid(&identity), true);
+        let identity = Point::identity();
+        assert!(ecc.is_valid(&identity));
         // Further validity tests would require known finite points on this curve.
     }
 
     #[test]
     fn test_add() {
         let ecc = MontgomeryCurve::new(2, 3, 17, 19);
-        let identity = Point { x: 0, y: 1, z: 0 };
+        let identity = Point::identity();
         // Choose a sample point (the following values are for illustration).
         let p = Point { x: 5, y: 8, z: 1 };
         let sum = ecc.add(&identity, &p);
@@ -26,10 +48,75 @@ mod tests {
     #[test]
     fn test_mul() {
         let ecc = MontgomeryCurve::new(2, 3, 17, 19);
-        let identity = Point { x: 0, y: 1, z: 0 };
+        let identity = Point::identity();
         let p = Point { x: 5, y: 8, z: 1 };
 
-        let r0 = ecc.mul(0, &p);
+        let r0 = ecc.mul(&Scalar::from(0), &p);
         assert_eq!(r0, identity);
     }
+
+    #[test]
+    fn test_neg_reduces() {
+        let (ecc, points) = small_curve();
+        for p in &points {
+            let unreduced = Point::new(p.x + 17, p.y + 17);
+            assert_eq!(ecc.neg(&unreduced), ecc.neg(p));
+            assert_eq!(ecc.neg(&ecc.neg(p)), *p);
+            assert!(ecc.is_identity(&ecc.add(p, &ecc.neg(p))));
+        }
+        assert_eq!(ecc.neg(&Point::identity()), Point::identity());
+    }
+
+    #[test]
+    fn test_ladder_matches_chord_and_tangent() {
+        let (ecc, points) = small_curve();
+        assert!(!points.is_empty());
+        for p in &points {
+            let mut expected = ecc.zero.clone();
+            for n in 0..40i64 {
+                assert_eq!(ecc.ladder_mul(&n, p), expected);
+                let u = if expected == ecc.zero { 0 } else { expected.x };
+                assert_eq!(ecc.ladder(&n, &p.x), u);
+                expected = ecc.add(&expected, p);
+            }
+            assert_eq!(ecc.mul(&Scalar::from(7), p), ecc.ladder_mul(&7, p));
+        }
+    }
+
+    #[test]
+    fn test_recover_y() {
+        let (ecc, points) = small_curve();
+        for p in points.iter().filter(|p| p.y != 0) {
+            let q = ecc.add(p, p);
+            let qp = ecc.add(&q, p);
+            if q == ecc.zero || qp == ecc.zero {
+                continue;
+            }
+            assert_eq!(ecc.recover_y(p, &q.x, &qp.x).unwrap(), q);
+        }
+    }
+
+    #[test]
+    fn test_curve25519_ladder() {
+        // RFC 7748 §5.2, first X25519 test vector (scalar already clamped, u masked).
+        let q = (BigInt::from(1) << 255usize) - BigInt::from(19);
+        let ecc = MontgomeryCurve::new(BigInt::from(486662), BigInt::from(1), q, BigInt::from(0));
+        let k = dec("31029842492115040904895560451863089656472772604678260265531221036453811406496");
+        let u = dec("34426434033919594451155107781188821651316167215306631574996226621102155684838");
+        let expected = dec("37325765543539916631701301279660700968428932651319597985674090122993663859395");
+        assert_eq!(ecc.ladder(&k, &u), expected);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(MontgomeryCurve::try_new(3i64, 0, 17, 0), Err(EccError::InvalidParameters(_))));
+        let (ecc, points) = small_curve();
+        let off_curve = Point { x: 1, y: 1, z: 1 };
+        assert!(!ecc.is_valid(&off_curve));
+        assert_eq!(ecc.checked_add(&points[0], &off_curve), Err(EccError::NotOnCurve));
+        assert!(matches!(ecc.at(5), Err(EccError::Unsupported(_))));
+        // A point of order two has y = 0, so y cannot be recovered through it.
+        let two_torsion = Point { x: 0, y: 0, z: 1 };
+        assert_eq!(ecc.recover_y(&two_torsion, &5, &0), Err(EccError::NonInvertible));
+    }
 }
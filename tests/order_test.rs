@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves;
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::edwards_curve::EdwardsCurve;
+    use KissECC::error::EccError;
+    use KissECC::montgomery_curve::MontgomeryCurve;
+    use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::twisted_curve::TwistedCurve;
+    use KissECC::utils::Utils;
+    use KissECC::weierstrass_ecc::WeierstrassECC;
+    use num_bigint::BigInt;
+
+    /// Every finite point of a curve over F_q, by testing all (x, y).
+    fn points(ecc: &dyn EllipticCurve<i64>, q: i64) -> Vec<Point<i64>> {
+        (0..q)
+            .flat_map(|x| (0..q).map(move |y| Point::new(x, y)))
+            .filter(|p| ecc.is_valid(p) && !ecc.is_identity(p))
+            .collect()
+    }
+
+    /// The order by repeated addition.
+    fn naive_order(ecc: &dyn EllipticCurve<i64>, g: &Point<i64>) -> i64 {
+        let mut order = 1;
+        let mut current = g.clone();
+        while !ecc.is_identity(&current) {
+            current = ecc.add(&current, g);
+            order += 1;
+        }
+        order
+    }
+
+    fn assert_orders(ecc: &dyn EllipticCurve<i64>, q: i64) {
+        let points = points(ecc, q);
+        assert!(!points.is_empty());
+        for p in &points {
+            assert_eq!(ecc.order(p), Ok(naive_order(ecc, p)), "{:?} on {}", p, ecc.display());
+        }
+        assert_eq!(ecc.order(&Point::identity()), Ok(1));
+    }
+
+    #[test]
+    fn test_matches_naive_order() {
+        assert_orders(&WeierstrassECC::new(2i64, 3, 17), 17);
+        assert_orders(&WeierstrassECC::new(1i64, 8, 97), 97);
+        assert_orders(&WeierstrassECC::new(0i64, 7, 101), 101);
+        assert_orders(&WeierstrassECC::new(1i64, 0, 103), 103);
+        assert_orders(&EdwardsCurve::new(2i64, 3, 17), 17);
+        assert_orders(&EdwardsCurve::new(1i64, 2, 101), 101);
+        assert_orders(&TwistedCurve::new(2i64, 3, 17, 19), 17);
+        assert_orders(&MontgomeryCurve::new(3i64, 1, 17, 0), 17);
+        assert_orders(&MontgomeryCurve::new(5i64, 3, 101, 0), 101);
+    }
+
+    #[test]
+    fn test_order_above_q() {
+        // y² = x³ + x + 8 over F_97 is cyclic of order 116 > 97.
+        let ecc = WeierstrassECC::new(1i64, 8, 97);
+        let g = Point::new(0, 28);
+        assert_eq!(ecc.order(&g), Ok(116));
+        assert_eq!(ecc.order(&ecc.double(&g)), Ok(58));
+        assert_eq!(ecc.order(&ecc.mul(&Scalar::from(29), &g)), Ok(4));
+    }
+
+    #[test]
+    fn test_large_field() {
+        // y² = x³ + 3x + 7 over the 40-bit prime 2^40 − 87.
+        let q: BigInt = (BigInt::from(1) << 40) - 87;
+        assert!(Utils::is_probable_prime(&q));
+        let ecc = WeierstrassECC::new(BigInt::from(3), BigInt::from(7), q.clone());
+        let mut x = BigInt::from(1);
+        let g = loop {
+            if let Ok((p, _)) = ecc.at(x.clone()) {
+                break p;
+            }
+            x += 1;
+        };
+        let n = ecc.order(&g).unwrap();
+        let w = Utils::isqrt(&(&q * 4));
+        assert!(n <= &q + 1 + &w);
+        assert!(ecc.is_identity(&ecc.mul(&Scalar::new(n.clone()), &g)));
+        for (p, _) in Utils::factor(&n) {
+            assert!(!ecc.is_identity(&ecc.mul(&Scalar::new(&n / p), &g)));
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        assert_eq!(ecc.order(&Point::new(1, 1)), Err(EccError::NotOnCurve));
+        // Far too many baby steps for a 256-bit field.
+        let p256 = curves::p256::<BigInt>();
+        assert_eq!(p256.curve.order(&p256.generator), Err(EccError::OrderNotFound));
+    }
+
+    #[test]
+    fn test_factor() {
+        assert_eq!(Utils::factor(&1i64), vec![]);
+        assert_eq!(Utils::factor(&116i64), vec![(2, 2), (29, 1)]);
+        assert_eq!(Utils::factor(&(1009i64 * 1009 * 1013)), vec![(1009, 2), (1013, 1)]);
+        let p = BigInt::from(1_000_000_007);
+        let q = (BigInt::from(1) << 61) - 1;
+        assert_eq!(Utils::factor(&(&p * &q * 12)), vec![(BigInt::from(2), 2), (BigInt::from(3), 1), (p, 1), (q, 1)]);
+        assert_eq!(Utils::isqrt(&99i64), 9);
+        assert_eq!(Utils::isqrt(&100i64), 10);
+        assert_eq!(Utils::isqrt(&0i64), 0);
+    }
+}
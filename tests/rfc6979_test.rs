@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves::{self, NamedCurve};
+    use KissECC::dsa::DSA;
+    use KissECC::rfc6979::NonceGenerator;
+    use KissECC::scalar::Scalar;
+    use KissECC::utils::Utils;
+    use KissECC::weierstrass_ecc::WeierstrassECC;
+    use num_bigint::BigInt;
+    use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+
+    /// ECDSA on a named curve with its generator and prime order.
+    fn dsa(c: NamedCurve<WeierstrassECC<BigInt>, BigInt>) -> DSA<BigInt> {
+        DSA::with_order(c.generator, c.order, Box::new(c.curve))
+    }
+
+    /// Signs `message` deterministically and checks (r, s) and verification.
+    fn check<D>(dsa: &DSA<BigInt>, x: &str, message: &str, r: &str, s: &str)
+    where
+        D: Digest + sha2::digest::core_api::BlockSizeUser + Clone,
+    {
+        let d = Scalar::modulo(Utils::from_hex(x), &dsa.n);
+        let pk = dsa.ec.mul(&d, &dsa.g);
+        let signature = dsa.sign_with::<D>(message.as_bytes(), &d).unwrap();
+        assert_eq!(signature, (Utils::from_hex(r), Utils::from_hex(s)), "{} over {}", message, dsa.ec.display());
+        assert!(dsa.verify_with::<D>(message.as_bytes(), &signature, &pk));
+    }
+
+    #[test]
+    fn test_nonce_detailed_example() {
+        // Appendix A.1: K-163 group order, SHA-256 and the message "sample".
+        let q = Utils::from_hex("4000000000000000000020108a2e0cc0d99f8a5ef");
+        let x = Utils::from_hex("09a4d6792295a7f730fc3f2b49cbc0f62e862272f");
+        let mut nonces = NonceGenerator::<BigInt, Sha256>::new(&q, &x, &Sha256::digest(b"sample"));
+        assert_eq!(nonces.next_k(), Utils::from_hex("23af4074c90a02b3fe61d286d5c87f425e6bdd81b"));
+    }
+
+    #[test]
+    fn test_nonce_p256() {
+        let dsa = dsa(curves::p256());
+        let x = Utils::from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let k = NonceGenerator::<BigInt, Sha256>::new(&dsa.n, &x, &Sha256::digest(b"sample")).next_k();
+        assert_eq!(k, Utils::from_hex("a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60"));
+        let k = NonceGenerator::<BigInt, Sha256>::new(&dsa.n, &x, &Sha256::digest(b"test")).next_k();
+        assert_eq!(k, Utils::from_hex("d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0"));
+        let k = NonceGenerator::<BigInt, Sha512>::new(&dsa.n, &x, &Sha512::digest(b"sample")).next_k();
+        assert_eq!(k, Utils::from_hex("5fa81c63109badb88c1f367b47da606da28cad69aa22c4fe6ad7df73a7173aa5"));
+    }
+
+    #[test]
+    fn test_nonce_p521() {
+        let dsa = dsa(curves::p521());
+        let x = Utils::from_hex("00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538");
+        let k = NonceGenerator::<BigInt, Sha512>::new(&dsa.n, &x, &Sha512::digest(b"sample")).next_k();
+        assert_eq!(k, Utils::from_hex("01dae2ea071f8110dc26882d4d5eae0621a3256fc8847fb9022e2b7d28e6f10198b1574fdd03a9053c08a1854a168aa5a57470ec97dd5ce090124ef52a2f7ecbffd3"));
+    }
+
+    #[test]
+    fn test_ecdsa_p224_sha224() {
+        let dsa = dsa(curves::p224());
+        let x = "f220266e1105bfe3083e03ec7a3a654651f45e37167e88600bf257c1";
+        check::<Sha224>(&dsa, x, "sample",
+            "1cdfe6662dde1e4a1ec4cdedf6a1f5a2fb7fbd9145c12113e6abfd3e",
+            "a6694fd7718a21053f225d3f46197ca699d45006c06f871808f43ebc");
+        check::<Sha224>(&dsa, x, "test",
+            "c441ce8e261ded634e4cf84910e4c5d1d22c5cf3b732bb204dbef019",
+            "902f42847a63bdc5f6046ada114953120f99442d76510150f372a3f4");
+    }
+
+    #[test]
+    fn test_ecdsa_p256_sha256() {
+        let dsa = dsa(curves::p256());
+        let x = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+        check::<Sha256>(&dsa, x, "sample",
+            "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+            "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8");
+        check::<Sha256>(&dsa, x, "test",
+            "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+            "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083");
+
+        // `sign` defaults to SHA-256, so it reproduces the same signature every time.
+        let d = Scalar::modulo(Utils::from_hex(x), &dsa.n);
+        assert_eq!(dsa.sign(b"sample", &d), dsa.sign(b"sample", &d));
+        assert_eq!(dsa.sign(b"sample", &d), dsa.sign_with::<Sha256>(b"sample", &d));
+    }
+
+    #[test]
+    fn test_ecdsa_p384_sha384() {
+        let dsa = dsa(curves::p384());
+        let x = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+        check::<Sha384>(&dsa, x, "sample",
+            "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe46",
+            "99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8");
+        check::<Sha384>(&dsa, x, "test",
+            "8203b63d3c853e8d77227fb377bcf7b7b772e97892a80f36ab775d509d7a5feb0542a7f0812998da8f1dd3ca3cf023db",
+            "ddd0760448d42d8a43af45af836fce4de8be06b485e9b61b827c2f13173923e06a739f040649a667bf3b828246baa5a5");
+    }
+
+    #[test]
+    fn test_ecdsa_p521_sha512() {
+        let dsa = dsa(curves::p521());
+        let x = "00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b83538";
+        check::<Sha512>(&dsa, x, "sample",
+            "00c328fafcbd79dd77850370c46325d987cb525569fb63c5d3bc53950e6d4c5f174e25a1ee9017b5d450606add152b534931d7d4e8455cc91f9b15bf05ec36e377fa",
+            "00617cce7cf5064806c467f678d3b4080d6f1cc50af26ca209417308281b68af282623eaa63e5b5c0723d8b8c37ff0777b1a20f8ccb1dccc43997f1ee0e44da4a67a");
+    }
+}
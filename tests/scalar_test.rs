@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves::{self, NamedCurve};
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::edwards_curve::EdwardsCurve;
+    use KissECC::montgomery_curve::MontgomeryCurve;
+    use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::twisted_curve::TwistedCurve;
+    use KissECC::utils::Utils;
+    use KissECC::weierstrass_ecc::WeierstrassECC;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_modular_arithmetic() {
+        let n = 19i64;
+        let a = Scalar::modulo(15, &n);
+        let b = Scalar::modulo(-7, &n);
+        assert_eq!(*b.value(), 12);
+        assert_eq!(*(&a + &b).value(), 8);
+        assert_eq!(*(&a - &b).value(), 3);
+        assert_eq!(*(&a * &b).value(), 9);
+        assert_eq!(*(-&a).value(), 4);
+        let inv = a.inverse().unwrap();
+        assert_eq!(*(&a * &inv).value(), 1);
+        assert!(Scalar::modulo(0, &n).inverse().is_err());
+        assert!(Scalar::new(5i64).inverse().is_err());
+    }
+
+    #[test]
+    fn test_plain_integers_and_bits() {
+        let k: Scalar<i64> = Scalar::from(-6);
+        assert!(k.is_negative());
+        assert_eq!(k.magnitude(), 6);
+        assert_eq!(k.bits(), 3);
+        assert!(!k.bit(0) && k.bit(1) && k.bit(2));
+        assert_eq!(*(-k).value(), 6);
+    }
+
+    #[test]
+    fn test_random_in_range() {
+        let n = curves::p256::<BigInt>().order;
+        for _ in 0..20 {
+            let k = Scalar::random(&n);
+            assert!(*k.value() > BigInt::from(0) && *k.value() < n);
+            assert_eq!(k.order(), Some(&n));
+        }
+        for _ in 0..200 {
+            let k = Scalar::random(&3i64);
+            assert!(*k.value() == 1 || *k.value() == 2);
+        }
+    }
+
+    #[test]
+    fn test_negative_scalars() {
+        let w = WeierstrassECC::new(2i64, 3, 17);
+        let p = Point { x: 5, y: 6, z: 1 };
+        for k in 1..25 {
+            assert_eq!(w.mul(&Scalar::from(-k), &p), w.neg(&w.mul(&Scalar::from(k), &p)));
+        }
+
+        let e = EdwardsCurve::new(2i64, 3, 17);
+        let p = Point { x: 1, y: 3, z: 1 };
+        for k in 1..25 {
+            let sum = e.add(&e.mul(&Scalar::from(-k), &p), &e.mul(&Scalar::from(k), &p));
+            assert_eq!(sum, e.zero);
+        }
+
+        let t = TwistedCurve::new(2i64, 3, 17, 19);
+        for k in 1..25 {
+            let sum = t.add(&t.mul(&Scalar::from(-k), &p), &t.mul(&Scalar::from(k), &p));
+            assert_eq!(sum, t.zero);
+        }
+
+        let m = MontgomeryCurve::new(3i64, 1, 17, 0);
+        let p = Point { x: 5, y: 1, z: 1 };
+        assert!(m.is_valid(&p));
+        for k in 1..25 {
+            let sum = m.add(&m.mul(&Scalar::from(-k), &p), &m.mul(&Scalar::from(k), &p));
+            assert_eq!(sum, m.zero);
+        }
+    }
+
+    #[test]
+    fn test_p256_full_width_scalars() {
+        let NamedCurve { curve: ecc, generator: g, order: n, .. } = curves::p256::<BigInt>();
+        let identity = Point::identity();
+
+        assert_eq!(ecc.mul(&Scalar::new(n.clone()), &g), identity);
+        let minus_one = Scalar::modulo(BigInt::from(-1), &n);
+        assert_eq!(ecc.mul(&minus_one, &g), ecc.neg(&g));
+        assert_eq!(ecc.mul(&Scalar::from(-1), &g), ecc.neg(&g));
+
+        // Public key for the RFC 6979 A.2.5 private key.
+        let d = Scalar::modulo(Utils::from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"), &n);
+        let pk = ecc.mul(&d, &g);
+        assert_eq!(pk.x, Utils::from_hex("60fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"));
+        assert_eq!(pk.y, Utils::from_hex("7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299"));
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::error::EccError;
+    use KissECC::schnorr::Bip340;
+    use num_bigint::BigInt;
+
+    /// One row of the BIP-340 reference vectors.
+    struct Vector {
+        index: usize,
+        secret: Option<[u8; 32]>,
+        public: [u8; 32],
+        aux: Option<[u8; 32]>,
+        message: Vec<u8>,
+        signature: [u8; 64],
+        valid: bool,
+    }
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn array<const N: usize>(s: &str) -> Option<[u8; N]> {
+        (!s.is_empty()).then(|| hex(s).try_into().unwrap())
+    }
+
+    fn vectors() -> Vec<Vector> {
+        include_str!("bip340_vectors.csv")
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let fields: Vec<&str> = line.trim_end().split(',').collect();
+                Vector {
+                    index: fields[0].parse().unwrap(),
+                    secret: array(fields[1]),
+                    public: array(fields[2]).unwrap(),
+                    aux: array(fields[3]),
+                    message: hex(fields[4]),
+                    signature: array(fields[5]).unwrap(),
+                    valid: fields[6] == "TRUE",
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_curve() {
+        let bip = Bip340::<BigInt>::new();
+        let g = bip.generator();
+        assert!(bip.curve().is_valid(g));
+        assert_eq!(
+            format!("{:x}", g.y),
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        );
+    }
+
+    #[test]
+    fn test_bip340_vectors() {
+        let bip = Bip340::<BigInt>::new();
+        let vectors = vectors();
+        assert_eq!(vectors.len(), 19);
+        for v in &vectors {
+            if let (Some(secret), Some(aux)) = (v.secret, v.aux) {
+                assert_eq!(bip.public_key(&secret), Ok(v.public), "vector {}", v.index);
+                assert_eq!(bip.sign_with_aux(&secret, &v.message, &aux), Ok(v.signature), "vector {}", v.index);
+            }
+            assert_eq!(bip.verify(&v.public, &v.message, &v.signature), v.valid, "vector {}", v.index);
+        }
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let bip = Bip340::<BigInt>::new();
+        let vectors = vectors();
+        let batch: Vec<_> = vectors
+            .iter()
+            .map(|v| (&v.public, &v.message[..], &v.signature))
+            .collect();
+        let valid: Vec<_> = vectors.iter().zip(&batch).filter(|(v, _)| v.valid).map(|(_, b)| *b).collect();
+        assert!(bip.verify_batch(&valid));
+        assert!(bip.verify_batch(&[]));
+        // A single invalid signature fails the whole batch.
+        for (v, item) in vectors.iter().zip(&batch) {
+            if !v.valid {
+                let mut mixed = valid.clone();
+                mixed.insert(1, *item);
+                assert!(!bip.verify_batch(&mixed), "vector {}", v.index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sign_random_aux() {
+        let bip = Bip340::<BigInt>::new();
+        let secret = [0x42u8; 32];
+        let public = bip.public_key(&secret).unwrap();
+        let signature = bip.sign(&secret, b"hello").unwrap();
+        assert!(bip.verify(&public, b"hello", &signature));
+        assert!(!bip.verify(&public, b"hellp", &signature));
+    }
+
+    #[test]
+    fn test_invalid_secret() {
+        let bip = Bip340::<BigInt>::new();
+        assert!(matches!(bip.public_key(&[0u8; 32]), Err(EccError::InvalidParameters(_))));
+        let n: [u8; 32] = bip.order().to_bytes_be().1.try_into().unwrap();
+        assert!(matches!(bip.sign_with_aux(&n, b"", &[0u8; 32]), Err(EccError::InvalidParameters(_))));
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::error::EccError;
+    use KissECC::scalar::Scalar;
+    use KissECC::weierstrass_ecc::WeierstrassECC;
+    use num_bigint::BigInt;
+
+    /// #E(F_q) by trying every x-coordinate, plus the identity.
+    fn brute_force(ecc: &WeierstrassECC<i64>) -> i64 {
+        let finite: i64 = (0..ecc.q)
+            .map(|x| match ecc.at(x) {
+                Ok((p, n)) if p == n => 1,
+                Ok(_) => 2,
+                Err(_) => 0,
+            })
+            .sum();
+        finite + 1
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let primes = [5i64, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 97, 101, 257];
+        for q in primes {
+            for a in 0..4 {
+                for b in 0..4 {
+                    let Ok(ecc) = WeierstrassECC::try_new(a, b, q) else {
+                        continue;
+                    };
+                    assert_eq!(ecc.group_order(), Ok(brute_force(&ecc)), "{}", ecc.display());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_known_orders() {
+        assert_eq!(WeierstrassECC::new(2i64, 3, 17).group_order(), Ok(22));
+        assert_eq!(WeierstrassECC::new(1i64, 8, 97).group_order(), Ok(116));
+        // Anomalous: y² = x³ + 5x + 3 over F_23 has 23 points.
+        assert_eq!(WeierstrassECC::new(5i64, 3, 23).group_order(), Ok(23));
+        // Supersingular: y² = x³ + x over F_q with q ≡ 3 (mod 4) has q + 1 points.
+        assert_eq!(WeierstrassECC::new(1i64, 0, 1019).group_order(), Ok(1020));
+        // Counted with Legendre symbols.
+        assert_eq!(WeierstrassECC::new(1i64, 8, 1000003).group_order(), Ok(999183));
+        let ecc = WeierstrassECC::new(BigInt::from(1), BigInt::from(8), BigInt::from(1000003));
+        assert_eq!(ecc.group_order(), Ok(BigInt::from(999183)));
+    }
+
+    #[test]
+    fn test_group_order_kills_every_point() {
+        let q = (1i64 << 31) - 1;
+        let ecc = WeierstrassECC::new(3, 7, q);
+        let n = ecc.group_order().unwrap();
+        let t = q + 1 - n;
+        assert!(t * t <= 4 * q);
+        for x in 1..20 {
+            let Ok((p, _)) = ecc.at(x) else {
+                continue;
+            };
+            assert!(ecc.mul(&Scalar::new(n), &p).is_identity());
+            assert_eq!(n % ecc.order(&p).unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn test_group_order_is_not_point_order() {
+        // The group of y² = x³ + 2x + 3 over F_17 is cyclic of order 22, and
+        // not every point generates it.
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        let n = ecc.group_order().unwrap();
+        let orders: Vec<i64> = (0..17)
+            .filter_map(|x| ecc.at(x).ok())
+            .map(|(p, _)| ecc.order(&p).unwrap())
+            .collect();
+        assert!(orders.iter().all(|o| n % o == 0));
+        assert!(orders.iter().any(|&o| o < n));
+        assert!(orders.contains(&n));
+    }
+
+    #[test]
+    fn test_errors() {
+        // 21 is not prime.
+        let ecc = WeierstrassECC::new(2i64, 3, 21);
+        assert!(matches!(ecc.group_order(), Err(EccError::InvalidParameters(_))));
+    }
+}
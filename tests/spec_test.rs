@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves;
+    use KissECC::ecc::EllipticCurve;
+    use KissECC::error::EccError;
+    use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::spec::{CurveSpec, GeneratorSpec, Model, Number, Rigidity};
+    use num_bigint::BigInt;
+
+    const TOY: &str = r#"
+        name = "toy"
+        model = "weierstrass"
+        p = 17
+        a = 2
+        b = 3
+        order = 11
+        cofactor = 2
+
+        [generator]
+        x = 3
+        y = 6
+    "#;
+
+    const P256: &str = r#"{
+        "name": "P-256",
+        "oid": "1.2.840.10045.3.1.7",
+        "model": "weierstrass",
+        "p": "0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        "a": -3,
+        "b": "0x5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+        "order": "0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        "cofactor": 1,
+        "generator": {
+            "x": "48439561293906451759052585252797914202762949526041747995844080717082404635286",
+            "y": "0x4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        }
+    }"#;
+
+    fn edwards_toy(model: Model) -> CurveSpec {
+        // x² + y² = 1 + 3x²y² over F_17 has 24 points; (1, 0) has order 4.
+        CurveSpec {
+            name: "edwards toy".to_string(),
+            oid: None,
+            model,
+            p: 17.into(),
+            a: 1.into(),
+            b: 3.into(),
+            order: 4.into(),
+            cofactor: 6.into(),
+            rigidity: None,
+            generator: GeneratorSpec { x: 1.into(), y: 0.into() },
+        }
+    }
+
+    #[test]
+    fn test_load_toml() {
+        let spec = CurveSpec::from_toml(TOY).unwrap();
+        assert_eq!(spec.model, Model::Weierstrass);
+        let toy = spec.load::<i64>().unwrap();
+        assert_eq!(toy.name, "toy");
+        assert_eq!(toy.generator, Point::new(3, 6));
+        assert_eq!(toy.order, 11);
+        assert_eq!(toy.curve.display(), "(y**2 = x**3 + 2 * x + 3) mod 17");
+        assert_eq!(toy.curve.order(&toy.generator), Ok(11));
+        assert_eq!(CurveSpec::from_toml(&spec.to_toml()), Ok(spec));
+    }
+
+    #[test]
+    fn test_load_json() {
+        let spec = CurveSpec::from_json(P256).unwrap();
+        assert_eq!(spec.oid.as_deref(), Some("1.2.840.10045.3.1.7"));
+        let loaded = spec.load::<BigInt>().unwrap();
+        let p256 = curves::p256::<BigInt>();
+        assert_eq!(loaded.generator, p256.generator);
+        assert_eq!(loaded.order, p256.order);
+        let k = Scalar::new(BigInt::from(0x1234_5678));
+        assert_eq!(loaded.curve.mul(&k, &loaded.generator), p256.curve.mul(&k, &p256.generator));
+        assert_eq!(CurveSpec::from_json(&spec.to_json()), Ok(spec.clone()));
+        assert_eq!(CurveSpec::from_toml(&spec.to_toml()), Ok(spec));
+    }
+
+    #[test]
+    fn test_models() {
+        for model in [Model::Edwards, Model::TwistedEdwards] {
+            let loaded = edwards_toy(model).load::<i64>().unwrap();
+            assert_eq!(loaded.curve.order(&loaded.generator), Ok(4));
+        }
+        // Curve25519 with its standard base point.
+        let c = curves::curve25519::<BigInt>();
+        let spec = CurveSpec {
+            name: "Curve25519".to_string(),
+            oid: Some("1.3.101.110".to_string()),
+            model: Model::Montgomery,
+            p: Number::Text(c.curve.q.to_string()),
+            a: 486662.into(),
+            b: 1.into(),
+            order: Number::Text(c.order.to_string()),
+            cofactor: 8.into(),
+            rigidity: Some(Rigidity::FullyRigid),
+            generator: GeneratorSpec { x: 9.into(), y: Number::Text(c.generator.y.to_string()) },
+        };
+        assert!(spec.load::<BigInt>().is_ok());
+        assert!(spec.to_toml().contains("rigidity = \"fully_rigid\""));
+        assert_eq!(CurveSpec::from_toml(&spec.to_toml()), Ok(spec));
+    }
+
+    #[test]
+    fn test_rejections() {
+        let invalid = |spec: &CurveSpec| matches!(spec.load::<i64>(), Err(EccError::InvalidParameters(_)));
+        let toy = CurveSpec::from_toml(TOY).unwrap();
+
+        // 4a³ + 27b² = 4*(−3)³ + 27*2² = 0.
+        let mut singular = toy.clone();
+        singular.a = (-3).into();
+        singular.b = 2.into();
+        assert!(invalid(&singular));
+        let mut singular = edwards_toy(Model::Edwards);
+        singular.b = 18.into();
+        assert!(invalid(&singular));
+        let mut singular = edwards_toy(Model::Montgomery);
+        singular.a = 2.into();
+        assert!(invalid(&singular));
+
+        let mut off_curve = toy.clone();
+        off_curve.generator.y = 2.into();
+        assert!(matches!(off_curve.load::<i64>(), Err(EccError::NotOnCurve)));
+        let mut wrong_order = toy.clone();
+        wrong_order.order = 10.into();
+        assert!(invalid(&wrong_order));
+        let mut unreduced = toy.clone();
+        unreduced.generator.x = 22.into();
+        assert!(invalid(&unreduced));
+        let mut zero_cofactor = toy.clone();
+        zero_cofactor.cofactor = 0.into();
+        assert!(invalid(&zero_cofactor));
+        // h = 4 would give 44 points, more than 17 + 1 + 2√17.
+        let mut outside_hasse = toy.clone();
+        outside_hasse.cofactor = 4.into();
+        assert!(invalid(&outside_hasse));
+
+        let mut garbage = toy.clone();
+        garbage.b = "0xg".into();
+        assert!(matches!(garbage.load::<i64>(), Err(EccError::Encoding(_))));
+        garbage.b = "--3".into();
+        assert!(matches!(garbage.load::<i64>(), Err(EccError::Encoding(_))));
+        assert!(matches!(CurveSpec::from_toml("name = "), Err(EccError::Encoding(_))));
+        assert!(matches!(CurveSpec::from_json("{\"name\": \"x\"}"), Err(EccError::Encoding(_))));
+        let unknown_model = TOY.replace("weierstrass", "hessian");
+        assert!(matches!(CurveSpec::from_toml(&unknown_model), Err(EccError::Encoding(_))));
+    }
+
+    #[test]
+    fn test_load_rejects_multiple_of_order() {
+        // 22 = 2*11 is the number of points, within the Hasse bound for p = 17,
+        // and 22*G is the identity, but G has order 11.
+        let mut doubled = CurveSpec::from_toml(TOY).unwrap();
+        doubled.order = 22.into();
+        doubled.cofactor = 1.into();
+        assert!(matches!(doubled.load::<i64>(), Err(EccError::InvalidParameters(_))));
+        doubled.cofactor = 2.into();
+        assert!(matches!(doubled.load::<i64>(), Err(EccError::InvalidParameters(_))));
+        // The order-4 point of the Edwards toy curve is accepted, but not with order 8.
+        assert!(edwards_toy(Model::Edwards).load::<i64>().is_ok());
+        let mut edwards = edwards_toy(Model::Edwards);
+        edwards.order = 8.into();
+        edwards.cofactor = 3.into();
+        assert!(matches!(edwards.load::<i64>(), Err(EccError::InvalidParameters(_))));
+    }
+}
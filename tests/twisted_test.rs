@@ -1,24 +1,38 @@
 #[cfg(test)]
 mod tests {
+    use KissECC::curves;
+    use KissECC::error::EccError;
     use KissECC::ecc::{EllipticCurve};
     use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::field::Reduction;
     use KissECC::twisted_curve::TwistedCurve;
+    use num_bigint::BigInt;
+
+    fn dec(s: &str) -> BigInt {
+        BigInt::parse_bytes(s.as_bytes(), 10).unwrap()
+    }
+
+    /// edwards25519 (a = -1) over arbitrary-precision integers.
+    fn ed25519() -> TwistedCurve<BigInt> {
+        curves::ed25519().curve
+    }
 
     #[test]
     fn test_is_valid() {
         // Example parameters: a, b, q, order.
         let ecc = TwistedCurve::new(2, 3, 17, 19);
-        let invalid = Point { x: 5, y: 1, z: 0 };
-        assert_eq!(ecc.is_valid(&invalid), false);
-        let valid = Point { x: 1, y: 3, z: 0 };
-        assert_eq!(ecc.is_valid(&valid), true);
+        let invalid = Point { x: 5, y: 1, z: 1 };
+        assert!(!ecc.is_valid(&invalid));
+        let valid = Point { x: 1, y: 3, z: 1 };
+        assert!(ecc.is_valid(&valid));
     }
 
     #[test]
     fn test_add() {
         let ecc = TwistedCurve::new(2, 3, 17, 19);
         let identity = ecc.zero.clone();
-        let p = Point { x: 1, y: 3, z: 0 };
+        let p = Point { x: 1, y: 3, z: 1 };
         let sum = ecc.add(&identity, &p);
         assert_eq!(sum, p);
     }
@@ -27,12 +41,162 @@ mod tests {
     fn test_mul() {
         let ecc = TwistedCurve::new(2, 3, 17, 19);
         let identity = ecc.zero.clone();
-        let p = Point { x: 1, y: 3, z: 0 };
+        let p = Point { x: 1, y: 3, z: 1 };
 
-        let r0 = ecc.mul(0, &p);
+        let r0 = ecc.mul(&Scalar::from(0), &p);
         assert_eq!(r0, identity);
 
-        let r1 = ecc.mul(1, &p);
+        let r1 = ecc.mul(&Scalar::from(1), &p);
         assert_eq!(r1, p);
     }
+
+    #[test]
+    fn test_ed25519_bigint() {
+        let ecc = ed25519();
+        let by = dec("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+        let bx = dec("15112221349535400772501151409588531511454012693041857206046113283949847762202");
+        let b = Point { x: bx.clone(), y: by.clone(), z: BigInt::from(1) };
+        assert!(ecc.is_valid(&b));
+
+        // x recovery from y uses a 253-bit exponent.
+        let (p1, p2) = ecc.at(by).unwrap();
+        assert_eq!(p1, b);
+        assert_eq!(p2.x, ecc.q.clone() - bx);
+
+        let b2 = ecc.double(&b);
+        assert!(ecc.is_valid(&b2));
+        assert_eq!(ecc.mul(&Scalar::from(3), &b), ecc.add(&b2, &b));
+        assert!(ecc.is_valid(&ecc.mul(&Scalar::from(1000), &b)));
+    }
+
+    #[test]
+    fn test_ed25519_pseudo_mersenne() {
+        let ecc = ed25519();
+        assert_eq!(ecc.field().reduction(), Reduction::Generic);
+        let by = dec("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+        let (b, _) = ecc.at(by.clone()).unwrap();
+        let folded = ed25519().with_reduction(Reduction::detect(&ecc.q));
+        assert_eq!(folded.field().reduction(), Reduction::PseudoMersenne);
+        assert_eq!(ecc.mul(&Scalar::from(12345), &b), folded.mul(&Scalar::from(12345), &b));
+    }
+
+    /// Every affine point of the curve, found by trying all y with `at`.
+    fn all_points(ecc: &TwistedCurve<i64>) -> Vec<Point<i64>> {
+        let mut points = Vec::new();
+        for y in 0..17 {
+            if let Ok((p1, p2)) = ecc.at(y) {
+                points.push(p1.clone());
+                if p2 != p1 {
+                    points.push(p2);
+                }
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_extended_matches_affine() {
+        let ecc = TwistedCurve::new(2i64, 3, 17, 19);
+        let points = all_points(&ecc);
+        assert!(points.len() > 4);
+        for p in &points {
+            let ep = ecc.to_extended(p);
+            for q in &points {
+                let eq = ecc.to_extended(q);
+                assert_eq!(ecc.to_affine(&ecc.extended_add(&ep, &eq)), Ok(ecc.add(p, q)));
+            }
+            assert_eq!(ecc.to_affine(&ecc.extended_double(&ep)), Ok(ecc.add(p, p)));
+            assert_eq!(ecc.double(p), ecc.add(p, p));
+
+            let mut expected = ecc.zero.clone();
+            for n in 0..30 {
+                assert_eq!(ecc.mul(&Scalar::from(n), p), expected);
+                expected = ecc.add(&expected, p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ed25519_extended_mul() {
+        let ecc = ed25519();
+        let by = dec("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+        let (b, _) = ecc.at(by).unwrap();
+        // Affine double-and-add reference.
+        let mut expected = ecc.zero.clone();
+        let mut m2 = b.clone();
+        let mut n = 0x5eed_1234;
+        while n > 0 {
+            if n & 1 == 1 {
+                expected = ecc.edwards_add(&expected, &m2);
+            }
+            m2 = ecc.edwards_add(&m2, &m2);
+            n >>= 1;
+        }
+        assert_eq!(ecc.mul(&Scalar::from(0x5eed_1234), &b), expected);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(TwistedCurve::try_new(2i64, 2, 17, 19), Err(EccError::InvalidParameters(_))));
+        assert!(matches!(TwistedCurve::try_new(2i64, 3, 1, 19), Err(EccError::InvalidParameters(_))));
+        // d = 4 is a square modulo 17, so some sums would reach Z = 0.
+        assert!(matches!(TwistedCurve::try_new(2i64, 4, 17, 19), Err(EccError::InvalidParameters(_))));
+        let ecc = TwistedCurve::try_new(2i64, 3, 17, 19).unwrap();
+        let off_curve = Point { x: 1, y: 1, z: 1 };
+        assert_eq!(ecc.checked_add(&ecc.zero, &off_curve), Err(EccError::NotOnCurve));
+        assert_eq!(ecc.order(&off_curve), Err(EccError::NotOnCurve));
+    }
+
+    fn bytes(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_encoding() {
+        let ecc = ed25519();
+        let by = dec("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+        let b = ecc.at(by).unwrap().0;
+        let encoded = bytes("5866666666666666666666666666666666666666666666666666666666666666");
+        assert_eq!(ecc.encoded_len(), 32);
+        assert_eq!(ecc.encode_point(&b), encoded);
+        assert_eq!(ecc.decode_point(&encoded), Ok(b.clone()));
+        // −B differs only in the sign bit.
+        let mut neg = encoded.clone();
+        neg[31] |= 0x80;
+        assert_eq!(ecc.encode_point(&ecc.neg(&b)), neg);
+        assert_eq!(ecc.decode_point(&neg), Ok(ecc.neg(&b)));
+        // The identity is (0, 1).
+        let mut one = vec![0u8; 32];
+        one[0] = 1;
+        assert_eq!(ecc.encode_point(&Point::identity()), one);
+        assert_eq!(ecc.decode_point(&one), Ok(Point::identity()));
+        for k in 1..20 {
+            let p = ecc.mul(&Scalar::from(k), &b);
+            assert_eq!(ecc.decode_point(&ecc.encode_point(&p)), Ok(p));
+        }
+    }
+
+    #[test]
+    fn test_decoding_rejections() {
+        let ecc = ed25519();
+        let encoding = |r: Result<Point<BigInt>, EccError>| matches!(r, Err(EccError::Encoding(_)));
+        assert!(encoding(ecc.decode_point(&[0u8; 31])));
+        // y = q is non-canonical.
+        let mut y_is_q = vec![0xffu8; 32];
+        y_is_q[0] = 0xed;
+        y_is_q[31] = 0x7f;
+        assert!(encoding(ecc.decode_point(&y_is_q)));
+        // y = 1 gives x = 0, which cannot carry the sign bit.
+        let mut negative_zero = vec![0u8; 32];
+        negative_zero[0] = 1;
+        negative_zero[31] = 0x80;
+        assert!(encoding(ecc.decode_point(&negative_zero)));
+        // y = 2 is not the y-coordinate of any point.
+        let mut no_point = vec![0u8; 32];
+        no_point[0] = 2;
+        assert_eq!(ecc.decode_point(&no_point), Err(EccError::NotOnCurve));
+    }
 }
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use KissECC::curves;
+    use KissECC::error::EccError;
+    use KissECC::spec::{CurveSpec, GeneratorSpec, Model, Number, Rigidity};
+    use KissECC::utils::Utils;
+    use KissECC::validate::{validate, Report};
+    use num_bigint::BigInt;
+
+    /// A specification without a generator; `validate` does not look at it.
+    fn spec<T: ToString>(model: Model, q: &T, a: &T, b: &T, n: &T, h: u8, rigidity: Option<Rigidity>) -> CurveSpec {
+        let number = |v: &T| Number::Text(v.to_string());
+        CurveSpec {
+            name: "test".to_string(),
+            oid: None,
+            model,
+            p: number(q),
+            a: number(a),
+            b: number(b),
+            order: number(n),
+            cofactor: Number::Int(h.into()),
+            rigidity,
+            generator: GeneratorSpec { x: 0.into(), y: 0.into() },
+        }
+    }
+
+    fn report(spec: &CurveSpec) -> Report<BigInt> {
+        validate(spec).unwrap()
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        let primes: Vec<i64> = (0..100).filter(Utils::is_probable_prime).collect();
+        assert_eq!(primes.len(), 25);
+        assert_eq!(primes[..5], [2, 3, 5, 7, 11]);
+        // Carmichael numbers and a strong pseudoprime to bases 2, 3 and 5.
+        for composite in [561i64, 1105, 41041, 25326001] {
+            assert!(!Utils::is_probable_prime(&composite));
+        }
+        let p256 = curves::p256::<BigInt>();
+        assert!(Utils::is_probable_prime(&p256.curve.q));
+        assert!(Utils::is_probable_prime(&p256.order));
+        assert!(!Utils::is_probable_prime(&(p256.order.clone() * &p256.curve.q)));
+    }
+
+    #[test]
+    fn test_rigid_curves_are_safe() {
+        let c = curves::curve25519::<BigInt>();
+        let rigid = Some(Rigidity::FullyRigid);
+        let r = report(&spec(Model::Montgomery, &c.curve.q, &c.curve.A, &c.curve.B, &c.order, 8, rigid));
+        assert!(r.is_safe(), "{}", r);
+        assert_eq!(r.twist_order, &r.q + 1 + &r.trace);
+        let c = curves::ed25519::<BigInt>();
+        assert!(report(&spec(Model::TwistedEdwards, &c.curve.q, &c.curve.a, &c.curve.b, &c.order, 8, rigid)).is_safe());
+        let c = curves::ed448::<BigInt>();
+        assert!(report(&spec(Model::Edwards, &c.curve.q, &c.curve.a, &c.curve.d, &c.order, 4, rigid)).is_safe());
+    }
+
+    #[test]
+    fn test_standard_curves() {
+        let c = curves::p256::<BigInt>();
+        let r = report(&spec(Model::Weierstrass, &c.curve.q, &c.curve.a, &c.curve.b, &c.order, 1, Some(Rigidity::Manipulable)));
+        assert_eq!(r.failures(), ["rigidity"]);
+        assert_eq!(r.rho_bits, 128);
+        assert_eq!(r.embedding_degree, None);
+
+        // The twist of P-224 has no large prime factor.
+        let c = curves::p224::<BigInt>();
+        let r = report(&spec(Model::Weierstrass, &c.curve.q, &c.curve.a, &c.curve.b, &c.order, 1, None));
+        assert_eq!(r.failures(), ["twist security", "rigidity"]);
+        assert_eq!(r.twist_prime, None);
+
+        // secp256k1 has CM by Z[(1 + √−3)/2]: t² − 4q = −3v².
+        let c = curves::secp256k1::<BigInt>();
+        let r = report(&spec(Model::Weierstrass, &c.curve.q, &c.curve.a, &c.curve.b, &c.order, 1, Some(Rigidity::SomewhatRigid)));
+        assert_eq!(r.failures(), ["CM discriminant"]);
+        assert_eq!(r.cm_discriminant, BigInt::from(3));
+    }
+
+    #[test]
+    fn test_toy_curve() {
+        // y² = x³ + 2x + 3 over F_17 has 22 = 2 * 11 points.
+        let r: Report<i64> = validate(&spec(Model::Weierstrass, &17, &2, &3, &11, 2, None)).unwrap();
+        assert!(r.field_prime && r.order_prime && r.nonsingular && r.hasse && r.cofactor_small);
+        assert_eq!(r.trace, -4);
+        assert_eq!(r.rho_bits, 2);
+        // 17 ≡ 6 (mod 11) has order 10.
+        assert_eq!(r.embedding_degree, Some(10));
+        assert!(r.mov_safe());
+        // t² − 4q = −52 = −4 * 13.
+        assert_eq!(r.cm_discriminant, 13);
+        assert_eq!(r.twist_order, 14);
+        assert_eq!(r.twist_prime, Some(7));
+        assert!(!r.is_safe());
+        assert!(r.to_string().contains("[FAIL] rho"));
+        assert!(r.to_string().contains("[ok] Hasse bound"));
+    }
+
+    #[test]
+    fn test_weak_curves() {
+        // Singular: 4*(−3)³ + 27*2² = 0.
+        let r: Report<i64> = validate(&spec(Model::Weierstrass, &17, &-3, &2, &11, 2, None)).unwrap();
+        assert!(!r.nonsingular);
+        let r: Report<i64> = validate(&spec(Model::Montgomery, &17, &2, &1, &11, 2, None)).unwrap();
+        assert!(!r.nonsingular);
+        let r: Report<i64> = validate(&spec(Model::Edwards, &17, &3, &20, &11, 2, None)).unwrap();
+        assert!(!r.nonsingular);
+
+        // y² = x³ + 5x + 3 over F_23 has exactly 23 points.
+        let r: Report<i64> = validate(&spec(Model::Weierstrass, &23, &5, &3, &23, 1, None)).unwrap();
+        assert!(r.anomalous);
+        assert_eq!(r.trace, 1);
+        assert!(r.failures().contains(&"not anomalous"));
+
+        // The supersingular y² = x³ + x over p = 4r − 1 has p + 1 = 4r points
+        // and embedding degree 2.
+        let q = BigInt::parse_bytes(b"6427752177035961102167848369364650410088811975131171341240811", 10).unwrap();
+        let n = (&q + 1) / 4;
+        let r = report(&spec(Model::Weierstrass, &q, &BigInt::from(1), &BigInt::from(0), &n, 4, Some(Rigidity::FullyRigid)));
+        assert_eq!(r.embedding_degree, Some(2));
+        assert_eq!(r.failures(), ["transfer (MOV)"]);
+
+        // Orders breaking the Hasse bound.
+        let r: Report<i64> = validate(&spec(Model::Weierstrass, &17, &2, &3, &31, 1, None)).unwrap();
+        assert!(!r.hasse);
+    }
+
+    #[test]
+    fn test_errors() {
+        let bad = spec(Model::Weierstrass, &17, &2, &3, &0, 1, None);
+        assert!(matches!(validate::<i64>(&bad), Err(EccError::InvalidParameters(_))));
+        let mut bad = spec(Model::Weierstrass, &17, &2, &3, &11, 2, None);
+        bad.p = "seventeen".into();
+        assert!(matches!(validate::<i64>(&bad), Err(EccError::Encoding(_))));
+    }
+}
@@ -1,28 +1,41 @@
 #[cfg(test)]
 mod tests {
+    use KissECC::curves::{self, NamedCurve};
+    use KissECC::error::EccError;
     use KissECC::ecc::{EllipticCurve};
     use KissECC::point::Point;
+    use KissECC::scalar::Scalar;
+    use KissECC::field::Reduction;
+    use KissECC::group::group_structure;
     use KissECC::weierstrass_ecc::WeierstrassECC;
+    use KissECC::utils::Utils;
+    use num_bigint::BigInt;
+
+    /// NIST P-256 over arbitrary-precision integers.
+    fn p256() -> (WeierstrassECC<BigInt>, Point<BigInt>) {
+        let p256 = curves::p256();
+        (p256.curve, p256.generator)
+    }
 
     #[test]
     fn test_is_valid() {
         let ecc = WeierstrassECC::new(  2, 3, 17 );
         // A point that is not on the curve.
-        let invalid = Point { x: 5, y: 1, z: 0 };
-        assert_eq!(ecc.is_valid(&invalid), false);
+        let invalid = Point { x: 5, y: 1, z: 1 };
+        assert!(!ecc.is_valid(&invalid));
         // A known valid point on y² = x³ + 2x + 3 mod 17.
-        let valid = Point { x: 5, y: 6, z: 0 };
-        assert_eq!(ecc.is_valid(&valid), true);
-        // The "zero" point (identity) is defined as (0, 0, 0) in this implementation.
-        let identity = Point { x: 0, y: 0, z: 0 };
-        assert_eq!(ecc.is_valid(&identity), true);
+        let valid = Point { x: 5, y: 6, z: 1 };
+        assert!(ecc.is_valid(&valid));
+        // The "zero" point (identity) is Point::identity() = (0, 1, 0), any point with z = 0.
+        let identity = Point::identity();
+        assert!(ecc.is_valid(&identity));
     }
 
     #[test]
     fn test_add() {
         let ecc = WeierstrassECC::new(  2, 3, 17 );
-        let identity = Point { x: 0, y: 0, z: 0 };
-        let p = Point { x: 5, y: 6, z: 0 };
+        let identity = Point::identity();
+        let p = Point { x: 5, y: 6, z: 1 };
         // Adding the identity should return the same point.
         let sum = ecc.add(&p, &identity);
         assert_eq!(sum, p);
@@ -31,20 +44,276 @@ mod tests {
     #[test]
     fn test_mul() {
         let ecc = WeierstrassECC::new(  2, 3, 17 );
-        let identity = Point { x: 0, y: 0, z: 0 };
+        let identity = Point::identity();
         let p = Point { x: 5, y: 6, z: 1 };
         let p2 = Point { x: 15, y: 12, z: 1 };
 
         // Multiplying by 0 should yield the identity.
-        let r0 = ecc.mul(0, &p);
+        let r0 = ecc.mul(&Scalar::from(0), &p);
         assert_eq!(r0, identity);
 
         // Multiplying by 1 should yield the same point.
-        let r1 = ecc.mul(1, &p);
+        let r1 = ecc.mul(&Scalar::from(1), &p);
         assert_eq!(r1, p);
 
-        let r2 = ecc.mul(2, &p);
+        let r2 = ecc.mul(&Scalar::from(2), &p);
+
+        assert!( r2.eq_affine(&p2, ecc.q));
+    }
+
+    #[test]
+    fn test_p256_bigint() {
+        let (ecc, g) = p256();
+        assert!(ecc.is_valid(&g));
+
+        let g2 = Point {
+            x: Utils::from_hex("7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978"),
+            y: Utils::from_hex("07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1"),
+            z: BigInt::from(1),
+        };
+        let g5 = Point {
+            x: Utils::from_hex("51590b7a515140d2d784c85608668fdfef8c82fd1f5be52421554a0dc3d033ed"),
+            y: Utils::from_hex("e0c17da8904a727d8ae1bf36bf8a79260d012f00d4d80888d1d0bb44fda16da4"),
+            z: BigInt::from(1),
+        };
+        assert_eq!(ecc.double(&g), g2);
+        assert_eq!(ecc.mul(&Scalar::from(2), &g), g2);
+        assert_eq!(ecc.mul(&Scalar::from(5), &g), g5);
+        assert_eq!(ecc.add(&ecc.mul(&Scalar::from(3), &g), &g2), g5);
+
+        // Recover G from its x-coordinate with a 256-bit Tonelli–Shanks.
+        let (r1, r2) = ecc.at(g.x.clone()).unwrap();
+        assert!(r1 == g || r2 == g);
+    }
+
+    #[test]
+    fn test_p256_reductions_agree() {
+        let (ecc, g) = p256();
+        // Curves start with generic reduction; the P-256 prime is a Solinas prime.
+        assert_eq!(ecc.field().reduction(), Reduction::Generic);
+        assert_eq!(Reduction::detect(&ecc.q), Reduction::Solinas);
+        let expected = ecc.mul(&Scalar::from(1000), &g);
+        for reduction in [Reduction::Montgomery, Reduction::Barrett, Reduction::Solinas] {
+            let (ecc, g) = p256();
+            let ecc = ecc.with_reduction(reduction);
+            assert_eq!(ecc.field().reduction(), reduction);
+            assert_eq!(ecc.mul(&Scalar::from(1000), &g), expected);
+        }
+    }
+
+    /// All affine points of y² = x³ + 2x + 3 mod 17.
+    fn small_curve_points(ecc: &WeierstrassECC<i64>) -> Vec<Point<i64>> {
+        ecc.points().skip(1).collect()
+    }
+
+    #[test]
+    fn test_add_matches_group_structure() {
+        // The group is cyclic of order 22, so every point is k*g for a single
+        // k mod 22 and P + Q must be (k_P + k_Q)*g.
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        let points: Vec<Point<i64>> = ecc.points().collect();
+        let s = group_structure(&ecc, &points).unwrap();
+        assert_eq!((s.n1, s.n2), (22, 1));
+        let multiples: Vec<Point<i64>> = (0..22).map(|k| ecc.mul(&Scalar::new(k), &s.g1)).collect();
+        let log = |p: &Point<i64>| multiples.iter().position(|m| m == p).unwrap();
+        for p in &points {
+            for q in &points {
+                assert_eq!(log(&ecc.add(p, q)), (log(p) + log(q)) % 22, "{:?} + {:?}", p, q);
+            }
+            assert_eq!(ecc.double(p), ecc.add(p, p));
+            assert!(ecc.add(p, &ecc.neg(p)).is_identity());
+        }
+    }
+
+    #[test]
+    fn test_jacobian_matches_affine() {
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        let points = small_curve_points(&ecc);
+        for p in &points {
+            for q in &points {
+                let expected = ecc.add(p, q);
+                let jp = ecc.to_jacobian(p);
+                let jq = ecc.to_jacobian(q);
+                assert_eq!(ecc.to_affine(&ecc.jacobian_add(&jp, &jq)), expected);
+                assert_eq!(ecc.to_affine(&ecc.mixed_add(&jp, q)), expected);
+                // Scale the representation to make sure Z ≠ 1 inputs are handled.
+                let doubled = ecc.jacobian_double(&jp);
+                assert_eq!(ecc.to_affine(&ecc.mixed_add(&doubled, q)), ecc.add(&ecc.double(p), q));
+            }
+            assert_eq!(ecc.to_affine(&ecc.jacobian_double(&ecc.to_jacobian(p))), ecc.double(p));
+        }
+    }
+
+    #[test]
+    fn test_mul_matches_repeated_addition() {
+        let ecc = WeierstrassECC::new(2i64, 3, 17);
+        let identity = Point::identity();
+        for p in small_curve_points(&ecc) {
+            let mut expected = identity.clone();
+            for n in 0..40 {
+                assert_eq!(ecc.mul(&Scalar::from(n), &p), expected);
+                expected = ecc.add(&expected, &p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            WeierstrassECC::try_new(0i64, 0, 17).err(),
+            Some(EccError::InvalidParameters("the curve is singular: 4a³ + 27b² ≡ 0 mod q"))
+        );
+        // y² = x³ − 3x + 2 = (x − 1)²(x + 2) has a double root.
+        assert!(matches!(WeierstrassECC::try_new(14i64, 2, 17), Err(EccError::InvalidParameters(_))));
+        assert!(matches!(WeierstrassECC::try_new(2i64, 3, 2), Err(EccError::InvalidParameters(_))));
+        assert!(matches!(WeierstrassECC::try_new(1i64, 1, 3), Err(EccError::InvalidParameters(_))));
+
+        let ecc = WeierstrassECC::try_new(2i64, 3, 17).unwrap();
+        let p = Point { x: 5, y: 6, z: 1 };
+        let off_curve = Point { x: 5, y: 1, z: 1 };
+        assert_eq!(ecc.checked_add(&p, &off_curve), Err(EccError::NotOnCurve));
+        assert_eq!(ecc.checked_add(&p, &p), Ok(ecc.double(&p)));
+        assert_eq!(ecc.order(&off_curve), Err(EccError::NotOnCurve));
+        // x³ + 2x + 3 = 3 is not a square modulo 17.
+        assert_eq!(ecc.at(0).err(), Some(EccError::NoSquareRoot));
+        assert!(matches!(ecc.at(17), Err(EccError::InvalidParameters(_))));
+    }
+
+    /// Checks the group law on every pair and triple of points, and that the
+    /// group order kills every point.
+    fn check_group(ecc: &WeierstrassECC<i64>, expected: usize) {
+        let points: Vec<Point<i64>> = ecc.points().collect();
+        assert_eq!(points.len(), expected, "{}", ecc.display());
+        let identity = &points[0];
+        for p in &points {
+            assert!(ecc.is_valid(p));
+            assert_eq!(ecc.add(p, identity), *p);
+            assert_eq!(ecc.add(p, &ecc.neg(p)), *identity);
+            assert_eq!(ecc.mul(&Scalar::new(expected as i64), p), *identity);
+            assert_eq!(ecc.mul(&Scalar::from(2), p), ecc.double(p));
+            for r in &points {
+                let sum = ecc.add(p, r);
+                assert!(ecc.is_valid(&sum));
+                assert_eq!(sum, ecc.add(r, p));
+                for s in &points {
+                    assert_eq!(ecc.add(&sum, s), ecc.add(p, &ecc.add(r, s)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_a_zero() {
+        // Small analogues of secp256k1: y² = x³ + 7.
+        check_group(&WeierstrassECC::new(0i64, 7, 13), 7);
+        check_group(&WeierstrassECC::new(0i64, 7, 31), 21);
+        let ecc = WeierstrassECC::new(0i64, 7, 13);
+        let g = ecc.at(7).unwrap().0;
+        assert_eq!(ecc.order(&g), Ok(7));
+    }
+
+    #[test]
+    fn test_b_zero() {
+        // y² = x³ + x: (0, 0) is a point of order 2, distinct from the identity (0, 1, 0).
+        let ecc = WeierstrassECC::new(1i64, 0, 23);
+        check_group(&ecc, 24);
+        let t = Point { x: 0, y: 0, z: 1 };
+        let identity = Point::identity();
+        assert!(ecc.is_valid(&t));
+        assert_ne!(t, identity);
+        assert_eq!(ecc.neg(&t), t);
+        assert_eq!(ecc.double(&t), identity);
+        assert_eq!(ecc.add(&t, &identity), t);
+        assert_eq!(ecc.mul(&Scalar::from(3), &t), t);
+        assert_eq!(ecc.order(&t), Ok(2));
+        assert_eq!(ecc.at(0), Ok((t.clone(), t)));
+    }
+
+    #[test]
+    fn test_identity() {
+        // y² = x³ + 2x + 4 over F_17: b = 4 is a square, so (0, ±2) are real points.
+        let ecc = WeierstrassECC::new(2i64, 4, 17);
+        let p = Point::new(0, 2);
+        assert!(ecc.is_valid(&p));
+        assert!(!ecc.is_identity(&p));
+        assert_eq!(ecc.neg(&p), Point::new(0, 15));
+        assert!(ecc.is_identity(&ecc.add(&p, &ecc.neg(&p))));
+        assert_eq!(ecc.add(&p, &Point::identity()), p);
+        assert_eq!(ecc.mul(&Scalar::from(16), &p), Point::identity());
+        assert_eq!(ecc.mul(&Scalar::from(17), &p), p);
+
+        // Any point flagged with z = 0 is the identity; finite points have z = 1.
+        assert_eq!(Point { x: 0, y: 0, z: 0 }, Point::identity());
+        assert_ne!(Point::new(0, 1), Point::identity());
+        assert!(ecc.is_identity(&Point { x: 5, y: 7, z: 0 }));
+        assert_eq!(ecc.identity(), Point::identity());
+        assert_eq!(ecc.at(0).unwrap().0.z, 1);
+    }
+
+    fn bytes(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sec1_encoding() {
+        let (ecc, g) = p256();
+        let p = ecc.mul(&Scalar::new(Utils::from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")), &g);
+        let uncompressed = bytes("0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299");
+        let compressed = bytes("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6");
+        assert_eq!(ecc.encode_point(&p, false), uncompressed);
+        assert_eq!(ecc.encode_point(&p, true), compressed);
+        assert_eq!(ecc.decode_point(&uncompressed), Ok(p.clone()));
+        assert_eq!(ecc.decode_point(&compressed), Ok(p.clone()));
+        let neg = ecc.neg(&p);
+        assert_eq!(ecc.decode_point(&ecc.encode_point(&neg, true)), Ok(neg));
+        assert_eq!(ecc.encode_point(&Point::identity(), true), vec![0x00]);
+        assert_eq!(ecc.decode_point(&[0x00]), Ok(Point::identity()));
+
+        // P-521 field elements take 66 bytes.
+        let NamedCurve { curve: p521, generator: g, .. } = curves::p521::<BigInt>();
+        let p = p521.mul(&Scalar::from(12345), &g);
+        let compressed = bytes("0300076ca1fa9613a70e8b2cecd8f64877968e32b91b16cd65166ca44b1439883a919e263c09cff2f7f39e770ebda9057d2f1198804a1c160f0bb99eb9ae6fab28f78b");
+        assert_eq!(p521.field_len(), 66);
+        assert_eq!(p521.encode_point(&p, true), compressed);
+        assert_eq!(p521.decode_point(&compressed), Ok(p.clone()));
+        assert_eq!(p521.decode_point(&p521.encode_point(&p, false)), Ok(p));
+    }
+
+    #[test]
+    fn test_sec1_rejections() {
+        let (ecc, g) = p256();
+        let mut uncompressed = ecc.encode_point(&g, false);
+        let compressed = ecc.encode_point(&g, true);
+        let encoding = |r: Result<Point<BigInt>, EccError>| matches!(r, Err(EccError::Encoding(_)));
+        assert!(encoding(ecc.decode_point(&[])));
+        assert!(encoding(ecc.decode_point(&[0x00, 0x00])));
+        assert!(encoding(ecc.decode_point(&uncompressed[..64])));
+        assert!(encoding(ecc.decode_point(&compressed[..32])));
+        let mut hybrid = uncompressed.clone();
+        hybrid[0] = 0x06;
+        assert!(encoding(ecc.decode_point(&hybrid)));
+        // x = q is not a canonical field element.
+        let mut x_too_large = vec![0x02];
+        x_too_large.extend(ecc.q.to_bytes_be().1);
+        assert!(encoding(ecc.decode_point(&x_too_large)));
+
+        let last = uncompressed.len() - 1;
+        uncompressed[last] ^= 1;
+        assert_eq!(ecc.decode_point(&uncompressed), Err(EccError::NotOnCurve));
+        // x = 1 is not the x-coordinate of any P-256 point.
+        let mut no_point = vec![0x02];
+        no_point.extend([0u8; 32]);
+        no_point[32] = 1;
+        assert_eq!(ecc.decode_point(&no_point), Err(EccError::NotOnCurve));
 
-        assert_eq!( r2.eq_affine(&p2, ecc.q), true);
+        // On y² = x³ + x the point (0, 0) has y = 0, which only 0x02 can encode.
+        let small = WeierstrassECC::new(1i64, 0, 23);
+        assert_eq!(small.encode_point(&Point::new(0, 0), true), vec![0x02, 0x00]);
+        assert_eq!(small.decode_point(&[0x02, 0x00]), Ok(Point::new(0, 0)));
+        assert_eq!(small.decode_point(&[0x04, 0x00, 0x00]), Ok(Point::new(0, 0)));
+        assert!(matches!(small.decode_point(&[0x03, 0x00]), Err(EccError::Encoding(_))));
     }
 }